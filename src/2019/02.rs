@@ -2,12 +2,12 @@ use advent_of_code::helpers::{Intcode, IntcodeComputer};
 
 fn simulate(intcode: Intcode, noun: usize, verb: usize) -> usize {
     let mut intcode = intcode;
-    intcode.set(1, noun);
-    intcode.set(2, verb);
+    intcode.set(1, noun as isize);
+    intcode.set(2, verb as isize);
 
     let mut computer = IntcodeComputer::new(intcode);
     computer.simulate_without_input();
-    computer.code.get(0)
+    computer.code.get(0) as usize
 }
 
 pub fn part_one(input: &str) -> Option<usize> {
@@ -21,13 +21,11 @@ pub fn part_two(input: &str) -> Option<usize> {
 
     (0..=99)
         .flat_map(|noun| (0..=99).map(move |verb| (noun, verb)))
-        .filter(|(noun, verb)| simulate(intcode.clone(), *noun, *verb) == 19690720)
-        .next()
+        .find(|(noun, verb)| simulate(intcode.clone(), *noun, *verb) == 19690720)
         .map(|(noun, verb)| 100 * noun + verb)
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 2);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+    let input = &advent_of_code::read_file("inputs", 2019, 2);
+    advent_of_code::solve!(part_one, part_two, input);
 }
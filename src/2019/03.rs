@@ -1,27 +1,7 @@
+use advent_of_code::helpers::{Direction, Point, Unit};
 use itertools::Itertools;
 use std::cmp::{max, min};
 
-type Unit = isize;
-
-enum Direction {
-    Right(Unit),
-    Left(Unit),
-    Up(Unit),
-    Down(Unit),
-}
-
-#[derive(PartialEq, Debug, Clone, Copy)]
-struct Point {
-    x: Unit,
-    y: Unit,
-}
-
-impl Point {
-    fn new(x: Unit, y: Unit) -> Self {
-        Self { x, y }
-    }
-}
-
 #[derive(PartialEq, Debug, Clone, Copy)]
 enum LineSegment {
     Horizontal { y: Unit, x_range: (Unit, Unit) },
@@ -54,13 +34,6 @@ impl LineSegment {
         }
     }
 
-    fn fixed(&self) -> Unit {
-        match self {
-            LineSegment::Horizontal { y, .. } => *y,
-            LineSegment::Vertical { x, .. } => *x,
-        }
-    }
-
     fn directionless_range(&self) -> (Unit, Unit) {
         let range = self.range();
         (min(range.0, range.1), max(range.0, range.1))
@@ -71,15 +44,17 @@ impl LineSegment {
         directionless_range.1 - directionless_range.0
     }
 
+    /// The steps from the start of the segment to a point on it. Segments keep the direction they
+    /// are walked in, so the start can lie on either side of the point.
     fn distance_to(&self, point: &Point) -> Unit {
         match self {
             LineSegment::Horizontal { x_range, y } => {
                 assert_eq!(point.y, *y);
-                point.x - x_range.0
+                (point.x - x_range.0).abs()
             }
             LineSegment::Vertical { y_range, x } => {
                 assert_eq!(point.x, *x);
-                point.y - y_range.0
+                (point.y - y_range.0).abs()
             }
         }
     }
@@ -99,7 +74,7 @@ impl LineSegment {
 
                 Some(Point::new(x, y))
             }
-            (LineSegment::Horizontal { y, x_range }, LineSegment::Vertical { x, y_range }) => {
+            (LineSegment::Horizontal { y, .. }, LineSegment::Vertical { x, .. }) => {
                 let x_range = self.directionless_range();
                 if !(x_range.0..=x_range.1).contains(&x) {
                     return None;
@@ -112,38 +87,24 @@ impl LineSegment {
 
                 Some(Point::new(x, y))
             }
-            _ => return None,
+            _ => None,
         }
     }
 }
 
-fn apply(point: Point, direction: Direction) -> Point {
-    match direction {
-        Direction::Right(amount) => Point::new(point.x + amount, point.y),
-        Direction::Left(amount) => Point::new(point.x - amount, point.y),
-        Direction::Up(amount) => Point::new(point.x, point.y + amount),
-        Direction::Down(amount) => Point::new(point.x, point.y - amount),
-    }
-}
-
 fn parse_input(input: &str) -> (Vec<LineSegment>, Vec<LineSegment>) {
     input
         .lines()
         .map(|line| {
             line.split(",")
                 .map(|element| {
-                    let amount = element[1..element.len()].parse().unwrap();
-
-                    match element.chars().next().unwrap() {
-                        'R' => Direction::Right(amount),
-                        'L' => Direction::Left(amount),
-                        'U' => Direction::Up(amount),
-                        'D' => Direction::Down(amount),
-                        _ => panic!(),
-                    }
+                    let amount: Unit = element[1..element.len()].parse().unwrap();
+                    let direction = Direction::from_char(element.chars().next().unwrap()).unwrap();
+
+                    direction.delta() * amount
                 })
-                .fold(vec![Point::new(0, 0)], |mut visited_points, direction| {
-                    let updated_point = apply(*visited_points.last().unwrap(), direction);
+                .fold(vec![Point::ORIGIN], |mut visited_points, offset| {
+                    let updated_point = *visited_points.last().unwrap() + offset;
                     visited_points.push(updated_point);
 
                     visited_points
@@ -164,8 +125,8 @@ pub fn part_one(input: &str) -> Option<isize> {
         .into_iter()
         .cartesian_product(second_wire)
         .filter_map(|(first, second)| first.intersection(second))
-        .filter(|intersection| *intersection != Point::new(0, 0))
-        .map(|intersection| intersection.x.abs() + intersection.y.abs())
+        .filter(|intersection| *intersection != Point::ORIGIN)
+        .map(|intersection| intersection.manhattan_distance(&Point::ORIGIN))
         .min()
 }
 
@@ -203,9 +164,8 @@ pub fn part_two(input: &str) -> Option<isize> {
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 3);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+    let input = &advent_of_code::read_file("inputs", 2019, 3);
+    advent_of_code::solve!(part_one, part_two, input);
 }
 
 #[cfg(test)]
@@ -221,14 +181,27 @@ mod tests {
     }
 
     #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 3);
-        assert_eq!(part_one(&input), Some(159));
+    fn distance_along_segment() {
+        let right = LineSegment::new((Point::new(2, 4), Point::new(8, 4)));
+        let left = LineSegment::new((Point::new(8, 4), Point::new(2, 4)));
+        let down = LineSegment::new((Point::new(3, 1), Point::new(3, 9)));
+        let up = LineSegment::new((Point::new(3, 9), Point::new(3, 1)));
+
+        assert_eq!(right.distance_to(&Point::new(5, 4)), 3);
+        assert_eq!(left.distance_to(&Point::new(5, 4)), 3);
+        assert_eq!(down.distance_to(&Point::new(3, 3)), 2);
+        assert_eq!(up.distance_to(&Point::new(3, 3)), 6);
     }
 
     #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 3);
-        assert_eq!(part_two(&input), Some(610));
+    fn test_negative_coordinates() {
+        // The first example mirrored through the origin, so every step walks towards negative
+        // coordinates.
+        let input = "L8,D5,R5,U3\nD7,L6,U4,R4\n";
+
+        assert_eq!(part_one(input), Some(6));
+        assert_eq!(part_two(input), Some(30));
     }
+
+    advent_of_code::example_tests!(2019, 3 => part_one, part_two);
 }
@@ -1,6 +1,6 @@
 use itertools::Itertools;
 
-pub fn part_one(input: &str) -> Option<usize> {
+pub fn part_one(_input: &str) -> Option<usize> {
     let minimum = 284639;
     let maximum = 748759;
 
@@ -25,7 +25,7 @@ pub fn part_one(input: &str) -> Option<usize> {
     )
 }
 
-pub fn part_two(input: &str) -> Option<usize> {
+pub fn part_two(_input: &str) -> Option<usize> {
     let minimum = 284639;
     let maximum = 748759;
 
@@ -55,7 +55,6 @@ pub fn part_two(input: &str) -> Option<usize> {
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 4);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+    let input = &advent_of_code::read_file("inputs", 2019, 4);
+    advent_of_code::solve!(part_one, part_two, input);
 }
@@ -0,0 +1,41 @@
+use advent_of_code::helpers::orbits::OrbitMap;
+
+pub fn part_one(input: &str) -> Option<usize> {
+    let map = OrbitMap::from_input(input).ok()?;
+
+    Some(map.total_orbits())
+}
+
+pub fn part_two(input: &str) -> Option<usize> {
+    let map = OrbitMap::from_input(input).ok()?;
+
+    map.transfers("YOU", "SAN")
+}
+
+fn main() {
+    let input = &advent_of_code::read_file("inputs", 2019, 6);
+    advent_of_code::solve!(part_one, part_two, input);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2019, 6);
+        assert_eq!(part_one(&input), Some(54));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2019, 6);
+        assert_eq!(part_two(&input), Some(4));
+    }
+
+    #[test]
+    fn test_invalid_orbits() {
+        assert_eq!(part_one("COM)B\nB-C"), None);
+        assert_eq!(part_two("COM)B\nA)B"), None);
+    }
+}
@@ -0,0 +1,40 @@
+use advent_of_code::helpers::amplifiers::Amplifiers;
+use advent_of_code::helpers::Intcode;
+
+pub fn part_one(input: &str) -> Option<isize> {
+    let amplifiers = Amplifiers::new(Intcode::from_input(input));
+
+    amplifiers
+        .best_phases(&[0, 1, 2, 3, 4], Amplifiers::run_chain)
+        .map(|(_, signal)| signal)
+}
+
+pub fn part_two(input: &str) -> Option<isize> {
+    let amplifiers = Amplifiers::new(Intcode::from_input(input));
+
+    amplifiers
+        .best_phases(&[5, 6, 7, 8, 9], Amplifiers::run_feedback_loop)
+        .map(|(_, signal)| signal)
+}
+
+fn main() {
+    let input = &advent_of_code::read_file("inputs", 2019, 7);
+    advent_of_code::solve!(part_one, part_two, input);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2019, 7);
+        assert_eq!(part_one(&input), Some(65210));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_example(2019, 7, 2);
+        assert_eq!(part_two(&input), Some(139629729));
+    }
+}
@@ -0,0 +1,46 @@
+use advent_of_code::helpers::space_image::{OcrRenderer, SpaceImage};
+
+const WIDTH: usize = 25;
+const HEIGHT: usize = 6;
+
+fn checksum(input: &str, width: usize, height: usize) -> Option<usize> {
+    let image = SpaceImage::from_input(input, width, height).ok()?;
+
+    image
+        .histograms()
+        .into_iter()
+        .min_by_key(|histogram| histogram[0])
+        .map(|histogram| histogram[1] * histogram[2])
+}
+
+pub fn part_one(input: &str) -> Option<usize> {
+    checksum(input, WIDTH, HEIGHT)
+}
+
+pub fn part_two(input: &str) -> Option<String> {
+    let image = SpaceImage::from_input(input, WIDTH, HEIGHT).ok()?;
+    image.render(&OcrRenderer).ok()?.ok()
+}
+
+fn main() {
+    let input = &advent_of_code::read_file("inputs", 2019, 8);
+    advent_of_code::solve!(part_one, part_two, input);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2019, 8);
+        assert_eq!(checksum(&input, 3, 2), Some(1));
+    }
+
+    #[test]
+    fn test_invalid_image() {
+        assert_eq!(part_one("0123"), None);
+        assert_eq!(part_two(&"3".repeat(WIDTH * HEIGHT)), None);
+        assert_eq!(part_two(&"1".repeat(WIDTH * HEIGHT)), None);
+    }
+}
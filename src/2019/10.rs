@@ -0,0 +1,46 @@
+use advent_of_code::helpers::asteroids::AsteroidField;
+
+pub fn part_one(input: &str) -> Option<usize> {
+    let field = AsteroidField::from_input(input);
+
+    field.best_station().map(|(_, visible)| visible)
+}
+
+pub fn part_two(input: &str) -> Option<isize> {
+    let field = AsteroidField::from_input(input);
+    let (station, _) = field.best_station()?;
+
+    field
+        .vaporization_order(station)
+        .nth(199)
+        .map(|asteroid| asteroid.x * 100 + asteroid.y)
+}
+
+fn main() {
+    let input = &advent_of_code::read_file("inputs", 2019, 10);
+    advent_of_code::solve!(part_one, part_two, input);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2019, 10);
+        assert_eq!(part_one(&input), Some(8));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_example(2019, 10, 2);
+        assert_eq!(part_one(&input), Some(210));
+        assert_eq!(part_two(&input), Some(802));
+    }
+
+    #[test]
+    fn test_part_two_too_few_asteroids() {
+        let input = advent_of_code::read_file("examples", 2019, 10);
+        assert_eq!(part_two(&input), None);
+    }
+}
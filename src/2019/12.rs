@@ -0,0 +1,37 @@
+use advent_of_code::helpers::moons::MoonSystem;
+
+fn energy_after(input: &str, steps: usize) -> i64 {
+    let mut system = MoonSystem::<3>::from_input(input);
+    system.simulate(steps);
+    system.total_energy()
+}
+
+pub fn part_one(input: &str) -> Option<i64> {
+    Some(energy_after(input, 1000))
+}
+
+pub fn part_two(input: &str) -> Option<i64> {
+    Some(MoonSystem::<3>::from_input(input).period())
+}
+
+fn main() {
+    let input = &advent_of_code::read_file("inputs", 2019, 12);
+    advent_of_code::solve!(part_one, part_two, input);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2019, 12);
+        assert_eq!(energy_after(&input, 10), 179);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2019, 12);
+        assert_eq!(part_two(&input), Some(2772));
+    }
+}
@@ -0,0 +1,41 @@
+use advent_of_code::helpers::reactions::Nanofactory;
+
+pub fn part_one(input: &str) -> Option<u64> {
+    let factory = Nanofactory::from_input(input).ok()?;
+
+    Some(factory.ore_for_fuel(1))
+}
+
+pub fn part_two(input: &str) -> Option<u64> {
+    let factory = Nanofactory::from_input(input).ok()?;
+
+    factory.max_fuel(1_000_000_000_000)
+}
+
+fn main() {
+    let input = &advent_of_code::read_file("inputs", 2019, 14);
+    advent_of_code::solve!(part_one, part_two, input);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2019, 14);
+        assert_eq!(part_one(&input), Some(13312));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2019, 14);
+        assert_eq!(part_two(&input), Some(82892753));
+    }
+
+    #[test]
+    fn test_invalid_reactions() {
+        assert_eq!(part_one("10 ORE => 10 A"), None);
+        assert_eq!(part_two("10 ORE => 0 FUEL"), None);
+    }
+}
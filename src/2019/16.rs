@@ -0,0 +1,66 @@
+use advent_of_code::helpers::fft::{digits_to_string, message, parse_signal};
+
+const PHASES: usize = 100;
+const MESSAGE_LENGTH: usize = 8;
+const REPEAT: usize = 10_000;
+/// The number of leading digits that give the offset of the message in part two.
+const OFFSET_LENGTH: usize = 7;
+
+pub fn part_one(input: &str) -> Option<String> {
+    let signal = parse_signal(input);
+
+    Some(digits_to_string(&message(
+        &signal,
+        1,
+        PHASES,
+        0,
+        MESSAGE_LENGTH,
+    )))
+}
+
+pub fn part_two(input: &str) -> Option<String> {
+    let signal = parse_signal(input);
+    let offset = digits_to_string(signal.get(..OFFSET_LENGTH)?)
+        .parse()
+        .ok()?;
+
+    if offset + MESSAGE_LENGTH > signal.len() * REPEAT {
+        return None;
+    }
+
+    Some(digits_to_string(&message(
+        &signal,
+        REPEAT,
+        PHASES,
+        offset,
+        MESSAGE_LENGTH,
+    )))
+}
+
+fn main() {
+    let input = &advent_of_code::read_file("inputs", 2019, 16);
+    advent_of_code::solve!(part_one, part_two, input);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2019, 16);
+        assert_eq!(part_one(&input), Some("24176176".to_string()));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_example(2019, 16, 2);
+        assert_eq!(part_two(&input), Some("84462026".to_string()));
+    }
+
+    #[test]
+    fn test_part_two_invalid_offset() {
+        assert_eq!(part_two("123456"), None);
+        assert_eq!(part_two("9999999"), None);
+    }
+}
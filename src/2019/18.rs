@@ -0,0 +1,39 @@
+use advent_of_code::helpers::vault::Vault;
+
+pub fn part_one(input: &str) -> Option<usize> {
+    Vault::from_input(input).shortest_path_to_collect_all_keys()
+}
+
+pub fn part_two(input: &str) -> Option<usize> {
+    let mut vault = Vault::from_input(input);
+    vault.split_entrance();
+    vault.shortest_path_to_collect_all_keys()
+}
+
+fn main() {
+    let input = &advent_of_code::read_file("inputs", 2019, 18);
+    advent_of_code::solve!(part_one, part_two, input);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2019, 18);
+        assert_eq!(part_one(&input), Some(86));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_example(2019, 18, 2);
+        assert_eq!(part_two(&input), Some(8));
+    }
+
+    #[test]
+    fn test_part_two_shared_doors() {
+        let input = advent_of_code::read_example(2019, 18, 3);
+        assert_eq!(part_two(&input), Some(72));
+    }
+}
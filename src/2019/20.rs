@@ -0,0 +1,50 @@
+use advent_of_code::helpers::donut_maze::DonutMaze;
+
+/// The deepest level the recursive search enters. Every level deeper costs at least one more step,
+/// so the search finds the shortest route whenever one exists within this depth, the limit only
+/// stops it from descending forever in a maze without a route.
+const MAX_DEPTH: usize = 1_000;
+
+pub fn part_one(input: &str) -> Option<usize> {
+    DonutMaze::from_input(input)
+        .shortest_route()
+        .map(|route| route.steps)
+}
+
+pub fn part_two(input: &str) -> Option<usize> {
+    DonutMaze::from_input(input)
+        .shortest_recursive_route(MAX_DEPTH)
+        .map(|route| route.steps)
+}
+
+fn main() {
+    let input = &advent_of_code::read_file("inputs", 2019, 20);
+    advent_of_code::solve!(part_one, part_two, input);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2019, 20);
+        assert_eq!(part_one(&input), Some(23));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2019, 20);
+        assert_eq!(part_two(&input), Some(26));
+    }
+
+    #[test]
+    fn test_part_two_deep() {
+        let input = advent_of_code::read_example(2019, 20, 2);
+        let maze = DonutMaze::from_input(&input);
+
+        assert_eq!(part_two(&input), Some(396));
+        assert_eq!(maze.shortest_recursive_route(10).unwrap().steps, 396);
+        assert!(maze.shortest_recursive_route(9).is_none());
+    }
+}
@@ -0,0 +1,85 @@
+use advent_of_code::helpers::shuffle::Shuffle;
+
+const DECK_SIZE: i64 = 10007;
+const LARGE_DECK_SIZE: i64 = 119315717514047;
+const SHUFFLE_COUNT: u64 = 101741582076661;
+
+pub fn part_one(input: &str) -> Option<i64> {
+    let shuffle = Shuffle::from_input(input, DECK_SIZE).ok()?;
+
+    Some(shuffle.position_of(2019))
+}
+
+pub fn part_two(input: &str) -> Option<i64> {
+    let shuffle = Shuffle::from_input(input, LARGE_DECK_SIZE).ok()?;
+
+    Some(shuffle.repeat(SHUFFLE_COUNT).card_at(2020))
+}
+
+fn main() {
+    let input = &advent_of_code::read_file("inputs", 2019, 22);
+    advent_of_code::solve!(part_one, part_two, input);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_deck() {
+        let input = advent_of_code::read_file("examples", 2019, 22);
+        let shuffle = Shuffle::from_input(&input, 10).unwrap();
+
+        assert_eq!(shuffle.deck(), vec![9, 2, 5, 8, 1, 4, 7, 0, 3, 6]);
+    }
+
+    #[test]
+    fn test_parts_are_inverse() {
+        let input = advent_of_code::read_file("examples", 2019, 22);
+        let shuffle = Shuffle::from_input(&input, DECK_SIZE).unwrap();
+
+        assert_eq!(shuffle.card_at(part_one(&input).unwrap()), 2019);
+    }
+
+    /// Shuffles an actual deck card by card, `times` times in a row.
+    fn shuffle_deck(input: &str, size: i64, times: usize) -> Vec<i64> {
+        let mut deck: Vec<i64> = (0..size).collect();
+
+        for line in input.lines().cycle().take(input.lines().count() * times) {
+            if line == "deal into new stack" {
+                deck.reverse();
+            } else if let Some(cut) = line.strip_prefix("cut ") {
+                let cut: i64 = cut.parse().unwrap();
+                deck.rotate_left(cut.rem_euclid(size) as usize);
+            } else if let Some(increment) = line.strip_prefix("deal with increment ") {
+                let increment: usize = increment.parse().unwrap();
+                let mut dealt = vec![0; deck.len()];
+                for (index, card) in deck.iter().enumerate() {
+                    dealt[index * increment % deck.len()] = *card;
+                }
+                deck = dealt;
+            }
+        }
+
+        deck
+    }
+
+    #[test]
+    fn test_repeated_inverse() {
+        // Part two repeats the shuffle and asks for the card at a position, which needs a prime
+        // deck size for the inverse. A deck of 10007 cards is small enough to shuffle by hand.
+        let input = advent_of_code::read_file("examples", 2019, 22);
+        let deck = shuffle_deck(&input, DECK_SIZE, 13);
+        let shuffle = Shuffle::from_input(&input, DECK_SIZE).unwrap().repeat(13);
+
+        for (position, card) in deck.into_iter().enumerate() {
+            assert_eq!(shuffle.card_at(position as i64), card);
+        }
+    }
+
+    #[test]
+    fn test_invalid_shuffle() {
+        assert_eq!(part_one("deal into new stack\nshuffle thoroughly"), None);
+        assert_eq!(part_two("cut x"), None);
+    }
+}
@@ -0,0 +1,43 @@
+use advent_of_code::helpers::network::{Network, NAT_ADDRESS};
+use advent_of_code::helpers::Intcode;
+
+const COMPUTERS: usize = 50;
+
+pub fn part_one(input: &str) -> Option<isize> {
+    let mut network = Network::new(&Intcode::from_input(input), COMPUTERS);
+    let log = network.run_until(|log| log.first_packet_to(NAT_ADDRESS).is_some())?;
+
+    log.first_packet_to(NAT_ADDRESS).map(|packet| packet.y)
+}
+
+pub fn part_two(input: &str) -> Option<isize> {
+    let mut network = Network::new(&Intcode::from_input(input), COMPUTERS);
+    let log = network.run_until(|log| log.first_repeated_nat_y().is_some())?;
+
+    log.first_repeated_nat_y()
+}
+
+fn main() {
+    let input = &advent_of_code::read_file("inputs", 2019, 23);
+    advent_of_code::solve!(part_one, part_two, input);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Every computer sends `(255, address, 3)` and then, for every packet it receives, sends the
+    // packet back to the NAT with `y` lowered by one until it reaches zero.
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2019, 23);
+        assert_eq!(part_one(&input), Some(3));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2019, 23);
+        assert_eq!(part_two(&input), Some(0));
+    }
+}
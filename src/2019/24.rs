@@ -0,0 +1,47 @@
+use advent_of_code::helpers::automaton::{Automaton, Layout, RecursiveLayout, Rule};
+
+fn bugs_after(input: &str, minutes: usize) -> u32 {
+    let automaton = Automaton::new(Rule::BUGS);
+    let layout = RecursiveLayout::new(Layout::from_input(input));
+
+    (0..minutes)
+        .fold(layout, |layout, _| automaton.step_recursive(&layout))
+        .count()
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let automaton = Automaton::new(Rule::BUGS);
+
+    Some(
+        automaton
+            .find_cycle(Layout::from_input(input))
+            .layout
+            .biodiversity(),
+    )
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    Some(bugs_after(input, 200))
+}
+
+fn main() {
+    let input = &advent_of_code::read_file("inputs", 2019, 24);
+    advent_of_code::solve!(part_one, part_two, input);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2019, 24);
+        assert_eq!(part_one(&input), Some(2129920));
+    }
+
+    #[test]
+    fn test_bugs_after() {
+        let input = advent_of_code::read_file("examples", 2019, 24);
+        assert_eq!(bugs_after(&input, 10), 99);
+    }
+}
@@ -0,0 +1,121 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::records::Status;
+use crate::runner::Answer;
+use std::path::PathBuf;
+use std::{fs, io};
+
+/// The accepted answers of a day, read from `src/YYYY/answers/DD.txt` which holds the answer to part
+/// one on its first line and the answer to part two on its second. Empty lines are unknown.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    parts: [Option<String>; 2],
+}
+
+fn answers_path(year: u16, day: u8) -> PathBuf {
+    crate::year_path(year)
+        .join("answers")
+        .join(format!("{day:02}.txt"))
+}
+
+impl Answers {
+    pub fn new(part_one: Option<String>, part_two: Option<String>) -> Self {
+        Self {
+            parts: [part_one, part_two],
+        }
+    }
+
+    pub fn parse(contents: &str) -> Self {
+        let mut lines = contents.lines().map(str::trim);
+        let mut next = || {
+            lines
+                .next()
+                .filter(|line| !line.is_empty())
+                .map(str::to_string)
+        };
+
+        Self {
+            parts: [next(), next()],
+        }
+    }
+
+    /// The answers of the day, all unknown when there is no answers file.
+    pub fn load(year: u16, day: u8) -> Self {
+        fs::read_to_string(answers_path(year, day))
+            .map(|contents| Self::parse(&contents))
+            .unwrap_or_default()
+    }
+
+    /// Stores the accepted answer of a part in the answers file of the day.
+    pub fn save(year: u16, day: u8, part: u8, answer: &str) -> io::Result<()> {
+        let mut answers = Self::load(year, day);
+        let index = usize::from(part)
+            .checked_sub(1)
+            .filter(|index| *index < answers.parts.len())
+            .ok_or_else(|| io::Error::other(format!("there is no part {part}")))?;
+        answers.parts[index] = Some(answer.to_string());
+
+        let contents = answers
+            .parts
+            .iter()
+            .map(|answer| answer.as_deref().unwrap_or_default().to_string() + "\n")
+            .collect::<String>();
+
+        let path = answers_path(year, day);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(path, contents)
+    }
+
+    pub fn expected(&self, part: u8) -> Option<&str> {
+        self.parts
+            .get(usize::from(part).checked_sub(1)?)?
+            .as_deref()
+    }
+
+    /// Compares an answer to the accepted answer of the part.
+    pub fn check(&self, part: u8, answer: Option<&Answer>) -> Status {
+        match (answer, self.expected(part)) {
+            (None, _) => Status::Unsolved,
+            (Some(_), None) => Status::Unknown,
+            (Some(answer), Some(expected)) if answer.to_string() == expected => Status::Correct,
+            (Some(_), Some(_)) => Status::Wrong,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse("1219070632396864\n");
+
+        assert_eq!(answers.expected(1), Some("1219070632396864"));
+        assert_eq!(answers.expected(2), None);
+        assert_eq!(answers.expected(3), None);
+        assert_eq!(Answers::parse("\nABC").expected(2), Some("ABC"));
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::parse("42\nHELLO");
+
+        assert_eq!(answers.check(1, Some(&Answer::Number(42))), Status::Correct);
+        assert_eq!(answers.check(1, Some(&Answer::Number(41))), Status::Wrong);
+        assert_eq!(
+            answers.check(2, Some(&Answer::Text("HELLO".to_string()))),
+            Status::Correct
+        );
+        assert_eq!(answers.check(2, None), Status::Unsolved);
+        assert_eq!(
+            Answers::default().check(1, Some(&Answer::Number(1))),
+            Status::Unknown
+        );
+    }
+}
@@ -0,0 +1,581 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt::Display;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::{env, io};
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// Identifies the tool to the Advent of Code servers, as requested by its maintainer.
+pub const USER_AGENT: &str = concat!(
+    "github.com/ricohageman/advent-of-code-2019/",
+    env!("CARGO_PKG_VERSION"),
+    " (built-in client)"
+);
+
+/// The environment variable with a way to reach the person running the client, such as an email
+/// address, which is added to the `User-Agent`.
+pub const CONTACT_ENV: &str = "AOC_CONTACT";
+
+/// The `User-Agent` sent with every request, with the contact when there is one.
+pub fn user_agent(contact: Option<&str>) -> String {
+    match contact.map(str::trim).filter(|contact| !contact.is_empty()) {
+        Some(contact) => format!("{USER_AGENT} contact: {contact}"),
+        None => USER_AGENT.to_string(),
+    }
+}
+
+/// The environment variable and the file name that hold the session cookie, the same ones aoc-cli
+/// reads.
+pub const SESSION_ENV: &str = "ADVENT_OF_CODE_SESSION";
+pub const SESSION_FILE: &str = ".adventofcode.session";
+
+/// Requests to the server are at least this far apart, also across runs that share a cache.
+pub const MIN_REQUEST_INTERVAL: Duration = Duration::from_secs(2);
+
+/// The file in the cache directory holding the time of the last request, in milliseconds since the
+/// Unix epoch.
+const LAST_REQUEST_FILE: &str = ".last-request";
+
+/// Part two is only on the puzzle page once part one is solved.
+const PART_TWO_HEADING: &str = "--- Part Two ---";
+
+#[derive(Debug)]
+pub enum ClientError {
+    NoSession,
+    Status(u16),
+    Transport(String),
+    Io(io::Error),
+    NoPuzzle,
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientError::NoSession => write!(
+                f,
+                "no session cookie found, set {SESSION_ENV} or write it to ~/{SESSION_FILE}."
+            ),
+            ClientError::Status(status) => write!(f, "the server responded with status {status}."),
+            ClientError::Transport(e) => write!(f, "could not reach the server: {e}"),
+            ClientError::Io(e) => write!(f, "could not access the cache: {e}"),
+            ClientError::NoPuzzle => write!(f, "the page does not contain a puzzle description."),
+        }
+    }
+}
+
+impl From<io::Error> for ClientError {
+    fn from(e: io::Error) -> Self {
+        ClientError::Io(e)
+    }
+}
+
+/// Reads the session cookie from the environment, or from the session file in the home or the
+/// config directory.
+pub fn find_session() -> Option<String> {
+    if let Ok(session) = env::var(SESSION_ENV) {
+        return Some(session.trim().to_string());
+    }
+
+    let home = env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(PathBuf::from);
+    let config = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| home.as_ref().map(|home| home.join(".config")));
+
+    [home, config]
+        .into_iter()
+        .flatten()
+        .flat_map(|dir| [dir.join(SESSION_FILE), dir.join(&SESSION_FILE[1..])])
+        .find_map(|path| fs::read_to_string(path).ok())
+        .map(|session| session.trim().to_string())
+        .filter(|session| !session.is_empty())
+}
+
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    cache_dir: PathBuf,
+    min_interval: Duration,
+}
+
+impl Client {
+    pub fn new(session: String) -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new()
+                .user_agent(&user_agent(env::var(CONTACT_ENV).ok().as_deref()))
+                .timeout(Duration::from_secs(30))
+                .build(),
+            base_url: BASE_URL.to_string(),
+            session,
+            cache_dir: PathBuf::from(".aoc-cache"),
+            min_interval: MIN_REQUEST_INTERVAL,
+        }
+    }
+
+    pub fn from_env() -> Result<Self, ClientError> {
+        find_session().map(Self::new).ok_or(ClientError::NoSession)
+    }
+
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn with_cache_dir(mut self, cache_dir: impl AsRef<Path>) -> Self {
+        self.cache_dir = cache_dir.as_ref().to_path_buf();
+        self
+    }
+
+    pub fn with_min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = min_interval;
+        self
+    }
+
+    fn cache_path(&self, path: &str) -> PathBuf {
+        self.cache_dir.join(path.trim_start_matches('/'))
+    }
+
+    fn cached(&self, path: &str) -> Option<String> {
+        fs::read_to_string(self.cache_path(path)).ok()
+    }
+
+    /// Sleeps until the minimum interval has passed since the last request, and stores the time of
+    /// this one in the cache directory so the next run waits for it as well.
+    fn wait_for_turn(&self) -> io::Result<()> {
+        let path = self.cache_dir.join(LAST_REQUEST_FILE);
+        let last_request = fs::read_to_string(&path)
+            .ok()
+            .and_then(|millis| millis.trim().parse().ok())
+            .map(|millis| UNIX_EPOCH + Duration::from_millis(millis));
+
+        if let Some(elapsed) = last_request.and_then(|last| last.elapsed().ok()) {
+            if elapsed < self.min_interval {
+                thread::sleep(self.min_interval - elapsed);
+            }
+        }
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        fs::create_dir_all(&self.cache_dir)?;
+        fs::write(path, now.as_millis().to_string())
+    }
+
+    /// Fetches the page at `path`, answering from the cache when it was fetched before unless
+    /// `refresh` is set.
+    pub fn fetch(&mut self, path: &str, refresh: bool) -> Result<String, ClientError> {
+        if !refresh {
+            if let Some(body) = self.cached(path) {
+                return Ok(body);
+            }
+        }
+
+        self.wait_for_turn()?;
+
+        let body = self
+            .agent
+            .get(&format!("{}{path}", self.base_url))
+            .set("Cookie", &format!("session={}", self.session))
+            .call()
+            .map_err(|e| match e {
+                ureq::Error::Status(status, _) => ClientError::Status(status),
+                e => ClientError::Transport(e.to_string()),
+            })?
+            .into_string()?;
+
+        let cache_path = self.cache_path(path);
+        if let Some(parent) = cache_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&cache_path, &body)?;
+
+        Ok(body)
+    }
+
+    pub fn input(&mut self, year: u16, day: u8, refresh: bool) -> Result<String, ClientError> {
+        self.fetch(&format!("/{year}/day/{day}/input"), refresh)
+    }
+
+    /// The puzzle description as markdown. A cached page without part two is fetched again, as
+    /// part two shows up once part one is solved.
+    pub fn puzzle(&mut self, year: u16, day: u8, refresh: bool) -> Result<String, ClientError> {
+        let path = format!("/{year}/day/{day}");
+        let complete = self
+            .cached(&path)
+            .is_some_and(|html| html.contains(PART_TWO_HEADING));
+        let html = self.fetch(&path, refresh || !complete)?;
+        let markdown = puzzle_to_markdown(&html);
+
+        match markdown.is_empty() {
+            true => Err(ClientError::NoPuzzle),
+            false => Ok(markdown),
+        }
+    }
+}
+
+/// Downloads the input and the puzzle description of a day into `src/YYYY/inputs` and
+/// `src/YYYY/puzzles`.
+pub fn download(client: &mut Client, year: u16, day: u8, refresh: bool) -> Result<(), ClientError> {
+    let input = client.input(year, day, refresh)?;
+    let puzzle = client.puzzle(year, day, refresh)?;
+
+    let input_path = crate::get_input_path(year, day);
+    let puzzle_path = crate::get_puzzle_path(year, day);
+    fs::create_dir_all(crate::year_path(year).join("inputs"))?;
+    fs::create_dir_all(crate::year_path(year).join("puzzles"))?;
+    fs::write(&input_path, input)?;
+    fs::write(&puzzle_path, puzzle + "\n")?;
+
+    println!("🎄 Successfully wrote input to \"{input_path}\".");
+    println!("🎄 Successfully wrote puzzle to \"{puzzle_path}\".");
+    Ok(())
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+/// The name of the tag and whether it closes, for the contents between `<` and `>`.
+fn tag_name(tag: &str) -> (String, bool) {
+    let closing = tag.starts_with('/');
+    let name = tag
+        .trim_start_matches('/')
+        .split(|char: char| char.is_whitespace() || char == '/')
+        .next()
+        .unwrap_or_default()
+        .to_lowercase();
+
+    (name, closing)
+}
+
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let start = tag.find(&format!("{name}=\""))? + name.len() + 2;
+    let end = start + tag[start..].find('"')?;
+
+    Some(decode_entities(&tag[start..end]))
+}
+
+/// Converts the markup used in puzzle descriptions to markdown.
+pub fn html_to_markdown(html: &str) -> String {
+    let mut markdown = String::new();
+    // Inline code is collected first, so emphasis inside it can be moved around it.
+    let mut code: Option<(String, bool)> = None;
+    let mut links = Vec::new();
+    let mut in_pre = false;
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let (text, tag) = match rest.find('<') {
+            Some(0) => {
+                let end = rest.find('>').map_or(rest.len(), |end| end + 1);
+                let tag = &rest[1..end.saturating_sub(1).max(1)];
+                rest = &rest[end..];
+                ("", Some(tag))
+            }
+            Some(start) => {
+                let text = &rest[..start];
+                rest = &rest[start..];
+                (text, None)
+            }
+            None => {
+                let text = rest;
+                rest = "";
+                (text, None)
+            }
+        };
+
+        if !text.is_empty() {
+            let text = decode_entities(text);
+            let text = match in_pre {
+                true => text,
+                false => text.replace('\n', " "),
+            };
+
+            match &mut code {
+                Some((content, _)) => content.push_str(&text),
+                None => markdown.push_str(&text),
+            }
+            continue;
+        }
+
+        let Some(tag) = tag else {
+            continue;
+        };
+        let (name, closing) = tag_name(tag);
+
+        match (name.as_str(), closing) {
+            ("h2", false) => markdown.push_str("## "),
+            ("h2", true) | ("p", true) | ("ul", true) => markdown.push_str("\n\n"),
+            ("pre", false) => {
+                in_pre = true;
+                markdown.push_str("```\n");
+            }
+            ("pre", true) => {
+                in_pre = false;
+                if !markdown.ends_with('\n') {
+                    markdown.push('\n');
+                }
+                markdown.push_str("```\n\n");
+            }
+            ("code", false) if !in_pre => code = Some((String::new(), false)),
+            ("code", true) if !in_pre => {
+                if let Some((content, emphasized)) = code.take() {
+                    match emphasized {
+                        true => markdown.push_str(&format!("*`{content}`*")),
+                        false => markdown.push_str(&format!("`{content}`")),
+                    }
+                }
+            }
+            ("em", _) => match &mut code {
+                Some((_, emphasized)) => *emphasized = true,
+                None if !in_pre => markdown.push('*'),
+                None => {}
+            },
+            ("li", false) => markdown.push_str("- "),
+            ("li", true) => markdown.push('\n'),
+            ("a", false) => {
+                links.push(attribute(tag, "href"));
+                markdown.push('[');
+            }
+            ("a", true) => match links.pop().flatten() {
+                Some(href) => markdown.push_str(&format!("]({href})")),
+                None => markdown.push(']'),
+            },
+            _ => {}
+        }
+    }
+
+    // Whitespace between tags ends up around block elements, code blocks are kept as they are.
+    let mut lines: Vec<&str> = Vec::new();
+    let mut in_block = false;
+
+    for line in markdown.lines() {
+        if line.trim_start().starts_with("```") {
+            in_block = !in_block;
+        }
+
+        let line = match in_block {
+            true if !line.trim_start().starts_with("```") => line,
+            _ => line.trim(),
+        };
+
+        if line.is_empty() && lines.last().is_none_or(|last| last.is_empty()) {
+            continue;
+        }
+        lines.push(line);
+    }
+
+    lines.join("\n").trim().to_string()
+}
+
+/// Converts the descriptions of both parts on a puzzle page to markdown, along with the answers
+/// that were already given.
+pub fn puzzle_to_markdown(html: &str) -> String {
+    let mut sections = Vec::new();
+    let mut rest = html;
+
+    loop {
+        let article = rest.find("<article");
+        let answer = rest.find("<p>Your puzzle answer was");
+
+        let (start, end_tag) = match (article, answer) {
+            (Some(article), Some(answer)) if answer < article => (answer, "</p>"),
+            (Some(article), _) => (article, "</article>"),
+            (None, Some(answer)) => (answer, "</p>"),
+            (None, None) => break,
+        };
+
+        let Some(end) = rest[start..]
+            .find(end_tag)
+            .map(|end| start + end + end_tag.len())
+        else {
+            break;
+        };
+
+        sections.push(html_to_markdown(&rest[start..end]));
+        rest = &rest[end..];
+    }
+
+    sections.join("\n\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+    use std::time::Instant;
+
+    const PAGE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 1: The Tyranny of the Rocket Equation ---</h2><p>Fuel required to launch a given <em>module</em> is based on its <em>mass</em>.</p>
+<ul>
+<li>For a mass of <code>12</code>, divide by 3 and round down to get <code>4</code>.</li>
+</ul>
+<pre><code>12
+14 &lt; 1969
+</code></pre>
+<p>What is the <a href="/2019/day/1/input" target="_blank">sum</a>? The answer is <code><em>34241</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>3393938</code>.</p>
+<form method="post"><input type="text" name="answer"/></form>
+</main></body></html>"#;
+
+    #[test]
+    fn test_puzzle_to_markdown() {
+        assert_eq!(
+            puzzle_to_markdown(PAGE),
+            "## --- Day 1: The Tyranny of the Rocket Equation ---
+
+Fuel required to launch a given *module* is based on its *mass*.
+
+- For a mass of `12`, divide by 3 and round down to get `4`.
+
+```
+12
+14 < 1969
+```
+
+What is the [sum](/2019/day/1/input)? The answer is *`34241`*.
+
+Your puzzle answer was `3393938`."
+        );
+    }
+
+    /// Serves `body` to every request on a local port and reports the request lines and headers.
+    fn stub_server(body: &'static str) -> (String, mpsc::Receiver<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let request = BufReader::new(&stream)
+                    .lines()
+                    .map(Result::unwrap)
+                    .take_while(|line| !line.is_empty())
+                    .collect::<Vec<_>>();
+
+                write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+                sender.send(request).unwrap();
+            }
+        });
+
+        (address, receiver)
+    }
+
+    #[test]
+    fn test_user_agent() {
+        assert!(USER_AGENT.starts_with("github.com/ricohageman/advent-of-code-2019/"));
+        assert_eq!(user_agent(None), USER_AGENT);
+        assert_eq!(user_agent(Some(" ")), USER_AGENT);
+        assert_eq!(
+            user_agent(Some("me@example.com")),
+            format!("{USER_AGENT} contact: me@example.com")
+        );
+    }
+
+    #[test]
+    fn test_fetch_with_cache() {
+        let (address, requests) = stub_server("1\n2\n3\n");
+        let cache_dir = env::temp_dir().join(format!("aoc-client-test-{}", std::process::id()));
+        let mut client = Client::new("secret".to_string())
+            .with_base_url(&address)
+            .with_cache_dir(&cache_dir)
+            .with_min_interval(Duration::ZERO);
+
+        assert_eq!(client.input(2019, 1, false).unwrap(), "1\n2\n3\n");
+        assert_eq!(client.input(2019, 1, false).unwrap(), "1\n2\n3\n");
+
+        let request = requests.recv().unwrap();
+        assert_eq!(request[0], "GET /2019/day/1/input HTTP/1.1");
+        assert!(request
+            .iter()
+            .any(|header| header.eq_ignore_ascii_case("cookie: session=secret")));
+        assert!(request.iter().any(|header| header.to_lowercase()
+            == format!(
+                "user-agent: {}",
+                user_agent(env::var(CONTACT_ENV).ok().as_deref()).to_lowercase()
+            )));
+
+        // The second call is answered from the cache, a refresh goes to the server again.
+        assert!(requests.try_recv().is_err());
+        client.input(2019, 1, true).unwrap();
+        assert!(requests.recv().is_ok());
+
+        fs::remove_dir_all(cache_dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_puzzle_until_part_two() {
+        let part_one = PAGE;
+        let both_parts = r#"<article><h2>--- Day 1 ---</h2><p>One.</p></article>
+<article><h2 id="part2">--- Part Two ---</h2><p>Two.</p></article>"#;
+
+        for (page, requests_per_run) in [(part_one, 2), (both_parts, 1)] {
+            let (address, requests) = stub_server(page);
+            let cache_dir = env::temp_dir().join(format!(
+                "aoc-client-test-puzzle-{requests_per_run}-{}",
+                std::process::id()
+            ));
+            let mut client = Client::new("secret".to_string())
+                .with_base_url(&address)
+                .with_cache_dir(&cache_dir)
+                .with_min_interval(Duration::ZERO);
+
+            client.puzzle(2019, 1, false).unwrap();
+            client.puzzle(2019, 1, false).unwrap();
+
+            for _ in 0..requests_per_run {
+                assert_eq!(requests.recv().unwrap()[0], "GET /2019/day/1 HTTP/1.1");
+            }
+            assert!(requests.try_recv().is_err());
+
+            fs::remove_dir_all(cache_dir).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_min_interval_across_clients() {
+        let (address, requests) = stub_server("1\n");
+        let cache_dir =
+            env::temp_dir().join(format!("aoc-client-test-wait-{}", std::process::id()));
+        let min_interval = Duration::from_millis(300);
+        let client = || {
+            Client::new("secret".to_string())
+                .with_base_url(&address)
+                .with_cache_dir(&cache_dir)
+                .with_min_interval(min_interval)
+        };
+
+        client().input(2019, 1, true).unwrap();
+        let start = Instant::now();
+        client().input(2019, 1, true).unwrap();
+
+        // The second client is a separate run, it only knows of the first through the cache.
+        assert!(start.elapsed() >= min_interval - Duration::from_millis(10));
+        assert_eq!(requests.iter().take(2).count(), 2);
+
+        fs::remove_dir_all(cache_dir).unwrap();
+    }
+}
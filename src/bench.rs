@@ -0,0 +1,475 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::answers::Answers;
+use crate::records::parse_numbers;
+use crate::runner::{self, Answer, PartResult};
+use crate::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+use std::{fs, io, process};
+
+/// A median that is this much slower than the baseline counts as a regression.
+pub const DEFAULT_THRESHOLD: f64 = 0.1;
+
+/// How to call a day, shown when its arguments cannot be parsed.
+pub const SOLVE_USAGE: &str = "cargo solve <day> [--release] -- [--bench] [--baseline <file>] [--save-baseline <file>] [--threshold <percent>]";
+
+/// Command line options shared by `cargo solve` and `cargo all`.
+#[derive(Clone, Debug)]
+pub struct Options {
+    pub bench: bool,
+    /// The baseline file to compare against.
+    pub baseline: Option<String>,
+    /// The baseline file to write the measured timings to.
+    pub save_baseline: Option<String>,
+    pub threshold: f64,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            bench: false,
+            baseline: None,
+            save_baseline: None,
+            threshold: DEFAULT_THRESHOLD,
+        }
+    }
+}
+
+impl Options {
+    /// The options of a day, which takes no other arguments.
+    pub fn from_env() -> Result<Self, pico_args::Error> {
+        let mut args = pico_args::Arguments::from_env();
+        let options = Self::parse(&mut args)?;
+        finish(args)?;
+
+        Ok(options)
+    }
+
+    /// Takes the options out of `args`, leaving any others to the caller.
+    pub fn parse(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        Ok(Self {
+            bench: args.contains("--bench"),
+            baseline: args.opt_value_from_str("--baseline")?,
+            save_baseline: args.opt_value_from_str("--save-baseline")?,
+            threshold: args
+                .opt_value_from_str::<_, f64>("--threshold")?
+                .map_or(DEFAULT_THRESHOLD, |percent| percent / 100_f64),
+        })
+    }
+}
+
+/// Fails on arguments that were not taken by any option.
+pub fn finish(args: pico_args::Arguments) -> Result<(), pico_args::Error> {
+    let unused = args.finish();
+
+    match unused.is_empty() {
+        true => Ok(()),
+        false => Err(pico_args::Error::ArgumentParsingFailed {
+            cause: format!("unexpected arguments {unused:?}"),
+        }),
+    }
+}
+
+/// Reports arguments that could not be parsed together with the `usage`, and exits.
+pub fn exit_with_usage(error: pico_args::Error, usage: &str) -> ! {
+    eprintln!("Failed to process arguments: {error}");
+    eprintln!("Usage: {usage}");
+    process::exit(1);
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct Config {
+    /// How long to run the solution before measuring.
+    pub warmup: Duration,
+    /// How long to keep measuring once `min_iterations` ran.
+    pub measurement: Duration,
+    pub min_iterations: usize,
+    pub max_iterations: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            warmup: Duration::from_millis(100),
+            measurement: Duration::from_secs(1),
+            min_iterations: 5,
+            max_iterations: 100_000,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Stats {
+    pub iterations: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let middle = sorted.len() / 2;
+        let median = match sorted.len() % 2 {
+            0 => (sorted[middle - 1] + sorted[middle]) / 2,
+            _ => sorted[middle],
+        };
+
+        let nanos = samples
+            .iter()
+            .map(|sample| sample.as_nanos() as f64)
+            .collect::<Vec<_>>();
+        let mean = nanos.iter().sum::<f64>() / nanos.len() as f64;
+        let variance = match nanos.len() {
+            1 => 0_f64,
+            count => {
+                nanos
+                    .iter()
+                    .map(|value| (value - mean).powi(2))
+                    .sum::<f64>()
+                    / (count - 1) as f64
+            }
+        };
+
+        Some(Self {
+            iterations: samples.len(),
+            min: sorted[0],
+            median,
+            mean: Duration::from_nanos(mean.round() as u64),
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+        })
+    }
+}
+
+impl std::fmt::Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{ANSI_ITALIC}(min: {:.2?}, median: {:.2?}, mean: {:.2?} ± {:.2?}, {} iterations){ANSI_RESET}",
+            self.min, self.median, self.mean, self.stddev, self.iterations
+        )
+    }
+}
+
+/// Runs `func` repeatedly after a warmup and returns its last answer together with the timing
+/// statistics. Input and answer pass through [`black_box`] so the calls are not optimized away.
+pub fn bench<T>(
+    func: impl Fn(&str) -> Option<T>,
+    input: &str,
+    config: &Config,
+) -> (Option<T>, Stats) {
+    let warmup = Instant::now();
+    let mut answer = black_box(func(black_box(input)));
+
+    while warmup.elapsed() < config.warmup {
+        answer = black_box(func(black_box(input)));
+    }
+
+    let mut samples = Vec::new();
+    let measurement = Instant::now();
+
+    while samples.len() < config.min_iterations
+        || (samples.len() < config.max_iterations && measurement.elapsed() < config.measurement)
+    {
+        let timer = Instant::now();
+        answer = black_box(func(black_box(input)));
+        samples.push(timer.elapsed());
+    }
+
+    (answer, Stats::from_samples(&samples).unwrap())
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Measurement {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub stats: Stats,
+}
+
+impl Measurement {
+    pub fn to_json(&self) -> String {
+        format!(
+            "{{\"year\":{},\"day\":{},\"part\":{},\"iterations\":{},\"min_ns\":{},\"median_ns\":{},\"mean_ns\":{},\"stddev_ns\":{}}}",
+            self.year,
+            self.day,
+            self.part,
+            self.stats.iterations,
+            self.stats.min.as_nanos(),
+            self.stats.median.as_nanos(),
+            self.stats.mean.as_nanos(),
+            self.stats.stddev.as_nanos()
+        )
+    }
+
+    pub fn from_json(line: &str) -> Option<Self> {
+        let object = parse_numbers(line)?;
+        let number = |key: &str| u64::try_from(*object.get(key)?).ok();
+        let duration = |key: &str| number(key).map(Duration::from_nanos);
+
+        Some(Self {
+            year: number("year")?.try_into().ok()?,
+            day: number("day")?.try_into().ok()?,
+            part: number("part")?.try_into().ok()?,
+            stats: Stats {
+                iterations: number("iterations")?.try_into().ok()?,
+                min: duration("min_ns")?,
+                median: duration("median_ns")?,
+                mean: duration("mean_ns")?,
+                stddev: duration("stddev_ns")?,
+            },
+        })
+    }
+}
+
+pub fn read_baseline(path: &str) -> io::Result<Vec<Measurement>> {
+    Ok(fs::read_to_string(path)?
+        .lines()
+        .filter_map(Measurement::from_json)
+        .collect())
+}
+
+/// Writes the measurements to the baseline file, keeping the entries of days and parts that were
+/// not measured this time.
+pub fn save_baseline(path: &str, measurements: &[Measurement]) -> io::Result<()> {
+    let mut baseline = match read_baseline(path) {
+        Ok(baseline) => baseline,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Vec::new(),
+        Err(e) => return Err(e),
+    };
+
+    baseline.retain(|old| {
+        !measurements
+            .iter()
+            .any(|new| (new.year, new.day, new.part) == (old.year, old.day, old.part))
+    });
+    baseline.extend_from_slice(measurements);
+    baseline.sort_by_key(|measurement| (measurement.year, measurement.day, measurement.part));
+
+    let lines = baseline
+        .iter()
+        .map(|measurement| measurement.to_json() + "\n")
+        .collect::<String>();
+
+    fs::write(path, lines)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Change {
+    /// There is no baseline for this day and part.
+    New,
+    Unchanged,
+    /// The median got faster, by this fraction of the baseline median.
+    Improved(f64),
+    /// The median got slower, by this fraction of the baseline median.
+    Regressed(f64),
+}
+
+/// Compares the medians, counting only changes larger than `threshold` times the baseline.
+pub fn compare(current: &Stats, baseline: Option<&Stats>, threshold: f64) -> Change {
+    let Some(baseline) = baseline else {
+        return Change::New;
+    };
+
+    let baseline_median = baseline.median.as_nanos().max(1) as f64;
+    let change = (current.median.as_nanos() as f64 - baseline_median) / baseline_median;
+
+    if change > threshold {
+        Change::Regressed(change)
+    } else if change < -threshold {
+        Change::Improved(-change)
+    } else {
+        Change::Unchanged
+    }
+}
+
+/// Finds the baseline of the same year, day and part as `current` and describes how it changed.
+pub fn compare_to_baseline(
+    current: &Measurement,
+    baseline: &[Measurement],
+    threshold: f64,
+) -> Change {
+    let previous = baseline.iter().find(|previous| {
+        (previous.year, previous.day, previous.part) == (current.year, current.day, current.part)
+    });
+
+    compare(
+        &current.stats,
+        previous.map(|previous| &previous.stats),
+        threshold,
+    )
+}
+
+impl std::fmt::Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Change::New => write!(f, "no baseline"),
+            Change::Unchanged => write!(f, "no change"),
+            Change::Improved(change) => write!(f, "improved by {:.1}%", change * 100_f64),
+            Change::Regressed(change) => {
+                write!(
+                    f,
+                    "{ANSI_BOLD}regressed by {:.1}%{ANSI_RESET}",
+                    change * 100_f64
+                )
+            }
+        }
+    }
+}
+
+/// Benchmarks one part of a day, prints its statistics and how they compare to the baseline. The
+/// returned result holds the median as its duration.
+pub fn report<T: Into<Answer>>(
+    year: u16,
+    day: u8,
+    part: u8,
+    func: impl Fn(&str) -> Option<T>,
+    input: &str,
+    answers: &Answers,
+) -> (PartResult, Measurement) {
+    let (answer, stats) = bench(func, input, &Config::default());
+    let measurement = Measurement {
+        year,
+        day,
+        part,
+        stats,
+    };
+
+    let answer = answer.map(Into::into);
+
+    println!("🎄 {ANSI_BOLD}Part {part}{ANSI_RESET} 🎄");
+    match &answer {
+        Some(answer) => println!("{} {stats}", runner::mark_answer(answer, part, answers)),
+        None => println!("not solved."),
+    }
+
+    let result = PartResult {
+        part,
+        answer,
+        duration: stats.median,
+    };
+
+    (result, measurement)
+}
+
+/// Prints how the measurement compares to the baseline file in the options, if there is one.
+pub fn report_change(measurement: &Measurement, options: &Options) -> Option<Change> {
+    let path = options.baseline.as_ref()?;
+
+    match read_baseline(path) {
+        Ok(baseline) => {
+            let change = compare_to_baseline(measurement, &baseline, options.threshold);
+            println!("{ANSI_ITALIC}vs. baseline:{ANSI_RESET} {change}");
+            Some(change)
+        }
+        Err(e) => {
+            eprintln!("Failed to read baseline \"{path}\": {e}");
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().copied().map(Duration::from_nanos).collect()
+    }
+
+    fn stats(median: u64) -> Stats {
+        Stats::from_samples(&nanos(&[median])).unwrap()
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(&nanos(&[40, 10, 30, 20])).unwrap();
+
+        assert_eq!(stats.iterations, 4);
+        assert_eq!(stats.min, Duration::from_nanos(10));
+        assert_eq!(stats.median, Duration::from_nanos(25));
+        assert_eq!(stats.mean, Duration::from_nanos(25));
+        assert_eq!(stats.stddev, Duration::from_nanos(13));
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn test_bench() {
+        let config = Config {
+            warmup: Duration::ZERO,
+            measurement: Duration::ZERO,
+            min_iterations: 3,
+            max_iterations: 10,
+        };
+        let (answer, stats) = bench(|input| Some(input.len()), "abc", &config);
+
+        assert_eq!(answer, Some(3));
+        assert_eq!(stats.iterations, 3);
+    }
+
+    #[test]
+    fn test_compare() {
+        assert_eq!(compare(&stats(100), None, 0.1), Change::New);
+        assert_eq!(
+            compare(&stats(105), Some(&stats(100)), 0.1),
+            Change::Unchanged
+        );
+        assert_eq!(
+            compare(&stats(150), Some(&stats(100)), 0.1),
+            Change::Regressed(0.5)
+        );
+        assert_eq!(
+            compare(&stats(50), Some(&stats(100)), 0.1),
+            Change::Improved(0.5)
+        );
+    }
+
+    #[test]
+    fn test_measurement_round_trip() {
+        let measurement = Measurement {
+            year: 2019,
+            day: 9,
+            part: 1,
+            stats: Stats::from_samples(&nanos(&[1_000, 1_200, 1_500])).unwrap(),
+        };
+
+        assert_eq!(
+            Measurement::from_json(&measurement.to_json()),
+            Some(measurement)
+        );
+        assert_eq!(
+            compare_to_baseline(&measurement, &[measurement], DEFAULT_THRESHOLD),
+            Change::Unchanged
+        );
+    }
+
+    #[test]
+    fn test_parse_options() {
+        let arguments = |values: &[&str]| {
+            pico_args::Arguments::from_vec(values.iter().map(Into::into).collect())
+        };
+
+        let mut args = arguments(&["--bench", "--threshold", "5", "--year", "2019"]);
+        let options = Options::parse(&mut args).unwrap();
+        assert!(options.bench);
+        assert_eq!(options.threshold, 0.05);
+        assert!(finish(args).is_err());
+
+        let mut args = arguments(&["--baseline", "base.json"]);
+        let options = Options::parse(&mut args).unwrap();
+        assert_eq!(options.baseline.as_deref(), Some("base.json"));
+        assert!(finish(args).is_ok());
+
+        assert!(Options::parse(&mut arguments(&["--threshold", "x"])).is_err());
+    }
+}
@@ -0,0 +1,76 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::{extract, get_module_path, get_puzzle_path, parse_year};
+use std::path::Path;
+use std::{fs, process};
+
+struct Args {
+    day: u8,
+    year: u16,
+    /// Overwrite example files that already exist, which may have been corrected by hand.
+    force: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        force: args.contains("--force"),
+        year: parse_year(&mut args)?,
+        day: args.free_from_str()?,
+    })
+}
+
+fn main() {
+    let Args { day, year, force } = match parse_args() {
+        Ok(args) => args,
+        Err(_) => {
+            eprintln!("Need to specify a day (as integer). example: `cargo extract 7`");
+            process::exit(1);
+        }
+    };
+
+    let day_padded = format!("{day:02}");
+    let puzzle_path = get_puzzle_path(year, day);
+    let module_path = get_module_path(year, day);
+
+    let Ok(puzzle) = fs::read_to_string(&puzzle_path) else {
+        eprintln!("No puzzle description at \"{puzzle_path}\", run `cargo download {day} --year {year}` first.");
+        process::exit(1);
+    };
+
+    let examples = extract::extract(&puzzle);
+
+    if examples.is_empty() {
+        eprintln!("No example blocks found in \"{puzzle_path}\".");
+        process::exit(1);
+    }
+
+    for (index, example) in examples.iter().enumerate() {
+        let example_path = format!("src/{year}/examples/{day_padded}-{}.txt", index + 1);
+
+        if !force && Path::new(&example_path).exists() {
+            println!("Kept existing example file \"{}\"", &example_path);
+            continue;
+        }
+
+        match fs::write(&example_path, extract::to_file(example)) {
+            Ok(_) => {
+                println!("Created example file \"{}\"", &example_path);
+            }
+            Err(e) => {
+                eprintln!("Failed to create example file: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
+    println!("---");
+
+    let source = fs::read_to_string(&module_path).unwrap_or_default();
+    if !source.contains("example_tests!") {
+        println!("🎄 Add `advent_of_code::example_tests!({year}, {day} => part_one, part_two);` to the tests in \"{module_path}\" to test the examples.");
+    }
+    println!("🎄 Check the extracted examples, the annotated answers are a best guess from the description.");
+}
@@ -2,37 +2,32 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::aoc_cli;
-use std::process;
+use advent_of_code::{aoc_cli, get_puzzle_path, markdown, parse_part, parse_year};
+use std::{fs, process};
 
 struct Args {
     day: u8,
-    year: Option<u16>,
+    year: u16,
+    part: Option<u8>,
 }
 
 fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
     Ok(Args {
+        part: args.opt_value_from_fn(["-p", "--part"], parse_part)?,
+        year: parse_year(&mut args)?,
         day: args.free_from_str()?,
-        year: args.opt_value_from_str(["-y", "--year"])?,
     })
 }
 
-fn main() {
-    let args = match parse_args() {
-        Ok(args) => args,
-        Err(e) => {
-            eprintln!("Failed to process arguments: {e}");
-            process::exit(1);
-        }
-    };
-
+/// Reads the description through aoc-cli, for days that were not downloaded yet.
+fn read_remote(args: &Args) {
     if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+        eprintln!("no puzzle description at \"{}\" and command \"aoc\" not found or not callable. Run \"cargo download {} --year {}\" first.", get_puzzle_path(args.year, args.day), args.day, args.year);
         process::exit(1);
     }
 
-    match aoc_cli::read(args.day, args.year) {
+    match aoc_cli::read(args.day, Some(args.year)) {
         Ok(cmd_output) => {
             if !cmd_output.status.success() {
                 process::exit(1);
@@ -44,3 +39,31 @@ fn main() {
         }
     }
 }
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}");
+            process::exit(1);
+        }
+    };
+
+    let Ok(puzzle) = fs::read_to_string(get_puzzle_path(args.year, args.day)) else {
+        read_remote(&args);
+        return;
+    };
+
+    let puzzle = match args.part {
+        Some(part) => match markdown::select_part(&puzzle, part) {
+            Some(puzzle) => puzzle,
+            None => {
+                eprintln!("the description of day {} has no part {part} (yet), run \"cargo download {} --year {} --refresh\" after solving part 1.", args.day, args.day, args.year);
+                process::exit(1);
+            }
+        },
+        None => &puzzle,
+    };
+
+    println!("{}", markdown::render(puzzle));
+}
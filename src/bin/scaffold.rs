@@ -2,8 +2,9 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use advent_of_code::{get_input_path, get_module_path, parse_year};
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
     process,
 };
@@ -17,9 +18,8 @@ pub fn part_two(input: &str) -> Option<usize> {
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", DAY);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+    let input = &advent_of_code::read_file("inputs", YEAR, DAY);
+    advent_of_code::solve!(part_one, part_two, input);
 }
 
 #[cfg(test)]
@@ -28,21 +28,29 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", DAY);
+        let input = advent_of_code::read_file("examples", YEAR, DAY);
         assert_eq!(part_one(&input), None);
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", DAY);
+        let input = advent_of_code::read_file("examples", YEAR, DAY);
         assert_eq!(part_two(&input), None);
     }
 }
 "###;
 
-fn parse_args() -> Result<u8, pico_args::Error> {
+struct Args {
+    day: u8,
+    year: u16,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
-    args.free_from_str()
+    Ok(Args {
+        year: parse_year(&mut args)?,
+        day: args.free_from_str()?,
+    })
 }
 
 fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
@@ -50,12 +58,68 @@ fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
 }
 
 fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
+}
+
+/// Adds the day to the `solutions!` list in the runner, keeping the list sorted.
+fn register_solution(year: u16, day: u8) -> Result<(), std::io::Error> {
+    let path = "src/main.rs";
+    let day_padded = format!("{day:02}");
+    let entry =
+        format!("    y{year}_day{day_padded}: {year}, {day} => \"{year}/{day_padded}.rs\",");
+
+    let runner = fs::read_to_string(path)?;
+    let mut lines = runner.lines().collect::<Vec<_>>();
+
+    let start = lines
+        .iter()
+        .position(|line| line.starts_with("advent_of_code::solutions!"))
+        .ok_or_else(|| std::io::Error::other("solutions! list not found"))?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| *line == "}")
+            .ok_or_else(|| std::io::Error::other("solutions! list is not closed"))?;
+
+    if lines[start..end].contains(&entry.as_str()) {
+        return Ok(());
+    }
+
+    let index = start
+        + 1
+        + lines[start + 1..end]
+            .iter()
+            .take_while(|line| line.trim_start() < entry.trim_start())
+            .count();
+    lines.insert(index, &entry);
+
+    fs::write(path, lines.join("\n") + "\n")
+}
+
+/// Declares the solution as a binary named `YYYY-DD` in the manifest.
+fn register_binary(year: u16, day: u8) -> Result<(), std::io::Error> {
+    let path = "Cargo.toml";
+    let name = format!("{year}-{day:02}");
+    let entry = format!(
+        "[[bin]]\nname = \"{name}\"\npath = \"{}\"\n",
+        get_module_path(year, day)
+    );
+
+    let manifest = fs::read_to_string(path)?;
+    if manifest.contains(&format!("name = \"{name}\"")) {
+        return Ok(());
+    }
+
+    fs::write(path, format!("{}\n\n{entry}", manifest.trim_end()))
 }
 
 fn main() {
-    let day = match parse_args() {
-        Ok(day) => day,
+    let Args { day, year } = match parse_args() {
+        Ok(args) => args,
         Err(_) => {
             eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
             process::exit(1);
@@ -64,9 +128,17 @@ fn main() {
 
     let day_padded = format!("{day:02}");
 
-    let input_path = format!("src/inputs/{day_padded}.txt");
-    let example_path = format!("src/examples/{day_padded}.txt");
-    let module_path = format!("src/bin/{day_padded}.rs");
+    let input_path = get_input_path(year, day);
+    let example_path = format!("src/{year}/examples/{day_padded}.txt");
+    let module_path = get_module_path(year, day);
+
+    // Every folder of a year, so commands that write to them work in a new year.
+    for folder in ["inputs", "examples", "puzzles", "answers", "guesses"] {
+        if let Err(e) = fs::create_dir_all(format!("src/{year}/{folder}")) {
+            eprintln!("Failed to create {folder} directory: {e}");
+            process::exit(1);
+        }
+    }
 
     let mut file = match safe_create_file(&module_path) {
         Ok(file) => file,
@@ -76,7 +148,12 @@ fn main() {
         }
     };
 
-    match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
+    match file.write_all(
+        MODULE_TEMPLATE
+            .replace("YEAR", &year.to_string())
+            .replace("DAY", &day.to_string())
+            .as_bytes(),
+    ) {
         Ok(_) => {
             println!("Created module file \"{}\"", &module_path);
         }
@@ -86,6 +163,26 @@ fn main() {
         }
     }
 
+    match register_solution(year, day) {
+        Ok(_) => {
+            println!("Registered day {} in \"src/main.rs\"", &day_padded);
+        }
+        Err(e) => {
+            eprintln!("Failed to register solution: {e}");
+            process::exit(1);
+        }
+    }
+
+    match register_binary(year, day) {
+        Ok(_) => {
+            println!("Added binary \"{year}-{day_padded}\" to \"Cargo.toml\"");
+        }
+        Err(e) => {
+            eprintln!("Failed to add binary: {e}");
+            process::exit(1);
+        }
+    }
+
     match create_file(&input_path) {
         Ok(_) => {
             println!("Created empty input file \"{}\"", &input_path);
@@ -107,8 +204,5 @@ fn main() {
     }
 
     println!("---");
-    println!(
-        "🎄 Type `cargo solve {}` to run your solution.",
-        &day_padded
-    );
+    println!("🎄 Type `cargo solve {year}-{day_padded}` to run your solution.");
 }
@@ -0,0 +1,66 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::{parse_bin_name, parse_year};
+use std::ffi::OsString;
+use std::{env, process};
+
+struct Args {
+    year: u16,
+    day: u8,
+    /// Everything after the day, passed on to `cargo run`, e.g. `--release -- --bench`.
+    forwarded: Vec<OsString>,
+}
+
+/// Accepts a day (`1`, `01`) of the `--year/-y` or default year, or a binary name (`2019-01`).
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let year = parse_year(&mut args)?;
+    let solution: String = args.free_from_str()?;
+
+    let (year, day) = match parse_bin_name(&solution) {
+        Some(solution) => solution,
+        None => (
+            year,
+            solution
+                .parse()
+                .map_err(|_| pico_args::Error::Utf8ArgumentParsingFailed {
+                    value: solution.clone(),
+                    cause: "expected a day or <year>-<day>".to_string(),
+                })?,
+        ),
+    };
+
+    Ok(Args {
+        year,
+        day,
+        forwarded: args.finish(),
+    })
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}");
+            process::exit(1);
+        }
+    };
+
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let bin = format!("{}-{:02}", args.year, args.day);
+
+    let status = process::Command::new(cargo)
+        .args(["run", "--bin", &bin])
+        .args(&args.forwarded)
+        .status();
+
+    match status {
+        Ok(status) => process::exit(status.code().unwrap_or(1)),
+        Err(e) => {
+            eprintln!("Failed to spawn cargo: {e}");
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,130 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::answers::Answers;
+use advent_of_code::records::{self, RECORDS_ENV};
+use advent_of_code::submissions::{Guess, History, Outcome};
+use advent_of_code::{aoc_cli, parse_part, parse_year};
+use std::{env, fs, process};
+
+struct Args {
+    day: u8,
+    part: u8,
+    answer: Option<String>,
+    year: u16,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        part: args
+            .opt_value_from_fn(["-p", "--part"], parse_part)?
+            .unwrap_or(1),
+        answer: args.opt_value_from_str(["-a", "--answer"])?,
+        year: parse_year(&mut args)?,
+        day: args.free_from_str()?,
+    })
+}
+
+/// Runs the solution of the day and reads the answer to the part from its records.
+fn solve(year: u16, day: u8, part: u8) -> Result<String, String> {
+    let name = format!("{year}-{day:02}");
+    let records_path = env::temp_dir().join(format!("aoc-submit-{name}-{}.jsonl", process::id()));
+
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let status = process::Command::new(cargo)
+        .args(["run", "--release", "--quiet", "--bin", &name])
+        .env(RECORDS_ENV, &records_path)
+        .status()
+        .map_err(|e| format!("failed to run the solution: {e}"))?;
+
+    let records = records::read(&records_path.to_string_lossy());
+    let _ = fs::remove_file(&records_path);
+
+    // A wrong answer for another part makes the solution exit with an error, so only fail when
+    // there is no answer at all.
+    records
+        .map_err(|e| format!("failed to read the answers of the solution ({status}): {e}"))?
+        .into_iter()
+        .find(|record| record.part == part)
+        .and_then(|record| record.answer)
+        .map(|answer| answer.to_string())
+        .ok_or_else(|| format!("the solution has no answer to part {part}."))
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}");
+            process::exit(1);
+        }
+    };
+
+    let answer = match args
+        .answer
+        .map_or_else(|| solve(args.year, args.day, args.part), Ok)
+    {
+        Ok(answer) => answer,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    let mut history = match History::load(args.year, args.day) {
+        Ok(history) => history,
+        Err(e) => {
+            eprintln!("Failed to read earlier guesses: {e}");
+            process::exit(1);
+        }
+    };
+
+    if let Err(refusal) = history.check(args.part, &answer) {
+        eprintln!("Not submitting \"{answer}\": {refusal}");
+        process::exit(1);
+    }
+
+    if aoc_cli::check().is_err() {
+        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+        process::exit(1);
+    }
+
+    println!(
+        "Submitting \"{answer}\" for {} day {:02} part {}...",
+        args.year, args.day, args.part
+    );
+
+    let response = match aoc_cli::submit(args.day, args.part, &answer, Some(args.year)) {
+        Ok(output) => String::from_utf8_lossy(&output.stdout).to_string(),
+        Err(e) => {
+            eprintln!("failed to submit with aoc-cli: {e}");
+            process::exit(1);
+        }
+    };
+
+    let outcome = Outcome::parse(&response);
+    println!("🎄 {outcome}");
+
+    let guess = Guess {
+        part: args.part,
+        answer: answer.clone(),
+        outcome: outcome.clone(),
+    };
+    if let Err(e) = history.record(args.year, args.day, guess) {
+        eprintln!("Failed to record the guess: {e}");
+    }
+
+    if outcome != Outcome::Correct {
+        process::exit(1);
+    }
+
+    match Answers::save(args.year, args.day, args.part, &answer) {
+        Ok(_) => println!(
+            "🎄 Stored the answer in \"src/{}/answers/{:02}.txt\".",
+            args.year, args.day
+        ),
+        Err(e) => eprintln!("Failed to store the answer: {e}"),
+    }
+}
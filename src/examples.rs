@@ -0,0 +1,334 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::answers::Answers;
+use crate::records::Status;
+use crate::runner::Answer;
+use std::fs;
+use std::path::PathBuf;
+
+/// Starts a section in an example file, optionally followed by the expected answers:
+/// `--- example part_one=6 part_two=30 ---`.
+pub const EXAMPLE_DELIMITER: &str = "--- example";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Example {
+    pub number: usize,
+    pub input: String,
+    pub answers: Answers,
+}
+
+fn example_path(year: u16, name: &str) -> PathBuf {
+    crate::year_path(year)
+        .join("examples")
+        .join(format!("{name}.txt"))
+}
+
+/// The expected answers annotated on a delimiter line, `None` for other lines.
+fn parse_delimiter(line: &str) -> Option<Answers> {
+    let annotations = line
+        .trim()
+        .strip_prefix(EXAMPLE_DELIMITER)?
+        .trim_end_matches('-');
+    let mut parts = [None, None];
+
+    for annotation in annotations.split_whitespace() {
+        match annotation.split_once('=') {
+            Some(("part_one", answer)) => parts[0] = Some(answer.to_string()),
+            Some(("part_two", answer)) => parts[1] = Some(answer.to_string()),
+            _ => panic!("invalid example annotation: '{annotation}'"),
+        }
+    }
+
+    let [part_one, part_two] = parts;
+    Some(Answers::new(part_one, part_two))
+}
+
+/// The delimiter line that starts an example with the given expected answers. Answers that contain
+/// whitespace cannot be annotated and are left out.
+pub fn delimiter(answers: &Answers) -> String {
+    let annotations = [(1, "part_one"), (2, "part_two")]
+        .into_iter()
+        .filter_map(|(part, name)| {
+            let answer = answers
+                .expected(part)
+                .filter(|answer| !answer.contains(char::is_whitespace))?;
+            Some(format!(" {name}={answer}"))
+        })
+        .collect::<String>();
+
+    format!("{EXAMPLE_DELIMITER}{annotations} ---")
+}
+
+/// Splits the contents of an example file into its sections. Text before the first delimiter is
+/// an example without expected answers, unless it is blank.
+pub fn parse(contents: &str) -> Vec<Example> {
+    let mut sections: Vec<(Answers, Vec<&str>)> = vec![(Answers::default(), Vec::new())];
+
+    for line in contents.lines() {
+        match parse_delimiter(line) {
+            Some(answers) => sections.push((answers, Vec::new())),
+            None => sections.last_mut().unwrap().1.push(line),
+        }
+    }
+
+    if sections[0].1.iter().all(|line| line.trim().is_empty()) {
+        sections.remove(0);
+    }
+
+    sections
+        .into_iter()
+        .enumerate()
+        .map(|(index, (answers, lines))| Example {
+            number: index + 1,
+            input: lines.join("\n") + "\n",
+            answers,
+        })
+        .collect()
+}
+
+/// The examples of a day: the numbered files `src/YYYY/examples/DD-1.txt`, `DD-2.txt`, … when
+/// they exist and the sections of `src/YYYY/examples/DD.txt` otherwise.
+pub fn load_all(year: u16, day: u8) -> Vec<Example> {
+    let numbered = (1..)
+        .map_while(|number| load_numbered(year, day, number))
+        .collect::<Vec<_>>();
+
+    if !numbered.is_empty() {
+        return numbered;
+    }
+
+    fs::read_to_string(example_path(year, &format!("{day:02}")))
+        .map(|contents| parse(&contents))
+        .unwrap_or_default()
+}
+
+fn load_numbered(year: u16, day: u8, number: usize) -> Option<Example> {
+    let contents = fs::read_to_string(example_path(year, &format!("{day:02}-{number}"))).ok()?;
+    let mut examples = parse(&contents);
+    assert!(
+        examples.len() <= 1,
+        "example file {day:02}-{number} has more than one section"
+    );
+
+    let mut example = examples.pop().unwrap_or(Example {
+        number,
+        input: String::new(),
+        answers: Answers::default(),
+    });
+    example.number = number;
+    Some(example)
+}
+
+/// The example with the given number, see [`load_all`].
+pub fn load(year: u16, day: u8, number: usize) -> Example {
+    load_numbered(year, day, number)
+        .or_else(|| {
+            load_all(year, day)
+                .into_iter()
+                .find(|example| example.number == number)
+        })
+        .unwrap_or_else(|| panic!("day {day} has no example {number}"))
+}
+
+/// Compares the answers of both parts to the answers the example expects. Fails with a message
+/// naming the example when one differs or when the example does not expect any answer.
+pub fn verify<T: Into<Answer>, U: Into<Answer>>(
+    example: &Example,
+    part_one: Option<T>,
+    part_two: Option<U>,
+) -> Result<(), String> {
+    let answers = [part_one.map(Into::into), part_two.map(Into::into)];
+    let mut checked = false;
+
+    for (part, answer) in (1..).zip(&answers) {
+        let Some(expected) = example.answers.expected(part) else {
+            continue;
+        };
+        checked = true;
+
+        if example.answers.check(part, answer.as_ref()) != Status::Correct {
+            return Err(format!(
+                "example {} part {part}: expected {expected}, got {}",
+                example.number,
+                answer
+                    .as_ref()
+                    .map_or("no answer".to_string(), ToString::to_string)
+            ));
+        }
+    }
+
+    match checked {
+        true => Ok(()),
+        false => Err(format!(
+            "example {} has no expected answers, annotate it with `{EXAMPLE_DELIMITER} part_one=… ---`",
+            example.number
+        )),
+    }
+}
+
+/// Like [`verify`], but panics with the message.
+pub fn check<T: Into<Answer>, U: Into<Answer>>(
+    example: &Example,
+    part_one: Option<T>,
+    part_two: Option<U>,
+) {
+    if let Err(message) = verify(example, part_one, part_two) {
+        panic!("{message}");
+    }
+}
+
+/// Runs both parts on every example of a day, see [`load_all`], and panics with the message of
+/// every example that fails after all of them ran. Examples without expected answers are
+/// skipped, but at least one example must have them.
+pub fn check_all<T: Into<Answer>, U: Into<Answer>>(
+    examples: &[Example],
+    part_one: impl Fn(&str) -> Option<T>,
+    part_two: impl Fn(&str) -> Option<U>,
+) {
+    let annotated = examples
+        .iter()
+        .filter(|example| example.answers != Answers::default())
+        .collect::<Vec<_>>();
+
+    assert!(
+        !annotated.is_empty(),
+        "there are no examples with expected answers, annotate them with `{EXAMPLE_DELIMITER} part_one=… ---`"
+    );
+
+    let failures = annotated
+        .into_iter()
+        .filter_map(|example| {
+            let result = verify(example, part_one(&example.input), part_two(&example.input));
+            println!(
+                "example {}: {}",
+                example.number,
+                if result.is_ok() { "ok" } else { "FAILED" }
+            );
+            result.err()
+        })
+        .collect::<Vec<_>>();
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+/// Generates a test that runs both parts on every example of a day and compares them to the
+/// annotated answers, so examples added to the files are tested without editing the day.
+///
+/// ```ignore
+/// advent_of_code::example_tests!(2019, 3 => part_one, part_two);
+/// ```
+#[macro_export]
+macro_rules! example_tests {
+    ($year:literal, $day:literal => $part_one:ident, $part_two:ident $(,)?) => {
+        #[test]
+        fn test_examples() {
+            $crate::examples::check_all(
+                &$crate::examples::load_all($year, $day),
+                $part_one,
+                $part_two,
+            );
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLES: &str = "--- example part_one=6 part_two=30 ---
+R8,U5,L5,D3
+U7,R6,D4,L4
+--- example part_two=abc ---
+1
+--- example ---
+2
+";
+
+    #[test]
+    fn test_parse() {
+        let examples = parse(EXAMPLES);
+
+        assert_eq!(examples.len(), 3);
+        assert_eq!(examples[0].input, "R8,U5,L5,D3\nU7,R6,D4,L4\n");
+        assert_eq!(
+            examples[0].answers,
+            Answers::new(Some("6".into()), Some("30".into()))
+        );
+        assert_eq!(examples[1].answers.expected(1), None);
+        assert_eq!(examples[1].answers.expected(2), Some("abc"));
+        assert_eq!(examples[2].number, 3);
+        assert_eq!(examples[2].input, "2\n");
+    }
+
+    #[test]
+    fn test_delimiter() {
+        let answers = Answers::new(Some("6".into()), Some("\"ABC\"".into()));
+
+        assert_eq!(
+            delimiter(&answers),
+            "--- example part_one=6 part_two=\"ABC\" ---"
+        );
+        assert_eq!(
+            parse(&format!("{}\n1\n", delimiter(&answers)))[0].answers,
+            answers
+        );
+        assert_eq!(
+            delimiter(&Answers::new(None, Some("a b".into()))),
+            "--- example ---"
+        );
+    }
+
+    #[test]
+    fn test_parse_without_delimiters() {
+        let examples = parse("1\n2\n");
+
+        assert_eq!(examples.len(), 1);
+        assert_eq!(examples[0].input, "1\n2\n");
+        assert_eq!(examples[0].answers, Answers::default());
+    }
+
+    #[test]
+    fn test_check() {
+        let examples = parse(EXAMPLES);
+
+        check(&examples[0], Some(6), Some(30));
+        check(&examples[1], None::<u32>, Some("abc"));
+    }
+
+    #[test]
+    #[should_panic(expected = "example 1 part 2: expected 30, got 31")]
+    fn test_check_wrong_answer() {
+        check(&parse(EXAMPLES)[0], Some(6), Some(31));
+    }
+
+    #[test]
+    #[should_panic(expected = "example 3 has no expected answers")]
+    fn test_check_without_answers() {
+        check(&parse(EXAMPLES)[2], Some(2), Some(2));
+    }
+
+    #[test]
+    fn test_check_all() {
+        check_all(
+            &parse(EXAMPLES),
+            |input| (input.len() == 24).then_some(6),
+            |input| Some(if input.len() == 24 { "30" } else { "abc" }),
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "example 1 part 1: expected 6, got 5\nexample 2 part 2: expected abc, got 30"
+    )]
+    fn test_check_all_reports_every_failure() {
+        check_all(&parse(EXAMPLES), |_| Some(5), |_| Some(30));
+    }
+
+    #[test]
+    #[should_panic(expected = "there are no examples with expected answers")]
+    fn test_check_all_without_answers() {
+        check_all(&parse("1\n"), |_| Some(1), |_| Some(1));
+    }
+}
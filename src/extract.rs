@@ -0,0 +1,175 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::answers::Answers;
+use crate::examples::delimiter;
+use crate::markdown::PART_TWO_HEADING;
+
+/// A code block from a puzzle description together with the emphasized answers given for it.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Example {
+    pub input: String,
+    pub answers: [Option<String>; 2],
+}
+
+/// The values of `*`…`*` spans on a line, which is how descriptions emphasize answers.
+fn emphasized_code(line: &str) -> Vec<&str> {
+    line.split("*`")
+        .skip(1)
+        .filter_map(|rest| rest.split_once("`*").map(|(value, _)| value))
+        .collect()
+}
+
+/// Collects the code blocks of a puzzle description as examples. The last emphasized value
+/// before the next block is taken as the answer of the part it appears in. In part two, answers
+/// only belong to blocks of part two itself, or to the first example when part two says it uses
+/// "the same example".
+pub fn extract(markdown: &str) -> Vec<Example> {
+    let mut examples: Vec<Example> = Vec::new();
+    let mut block: Option<Vec<&str>> = None;
+    // The number of blocks before part two, once it started.
+    let mut part_two: Option<usize> = None;
+    let mut same_example = false;
+
+    for line in markdown.lines() {
+        if line.starts_with("```") {
+            match block.take() {
+                Some(lines) => examples.push(Example {
+                    input: lines.join("\n"),
+                    answers: [None, None],
+                }),
+                None => block = Some(Vec::new()),
+            }
+            continue;
+        }
+
+        if let Some(lines) = &mut block {
+            lines.push(line);
+            continue;
+        }
+
+        if line.starts_with(PART_TWO_HEADING) {
+            part_two = Some(examples.len());
+        }
+
+        let (part, example) = match part_two {
+            None => (0, examples.last_mut()),
+            Some(start) => {
+                same_example |= line.to_lowercase().contains("same example");
+
+                match examples.len() > start {
+                    true => (1, examples.last_mut()),
+                    false => (1, examples.first_mut().filter(|_| same_example)),
+                }
+            }
+        };
+
+        if let (Some(example), Some(answer)) = (example, emphasized_code(line).last()) {
+            example.answers[part] = Some(answer.to_string());
+        }
+    }
+
+    examples
+}
+
+/// The contents of the example file for an example: a delimiter line with the answers found for
+/// it, followed by its input.
+pub fn to_file(example: &Example) -> String {
+    let [part_one, part_two] = example.answers.clone();
+
+    format!(
+        "{}\n{}\n",
+        delimiter(&Answers::new(part_one, part_two)),
+        example.input
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PUZZLE: &str = "## --- Day 1: Fuel ---
+
+For example:
+
+```
+12
+14
+```
+
+The total fuel is *`4`*, so the answer is *`6`*.
+
+```
+not an example
+```
+
+## --- Part Two ---
+
+With the same example, the answer is *`\"ABC\"`*.";
+
+    #[test]
+    fn test_extract() {
+        assert_eq!(
+            extract(PUZZLE),
+            vec![
+                Example {
+                    input: "12\n14".to_string(),
+                    answers: [Some("6".to_string()), Some("\"ABC\"".to_string())],
+                },
+                Example {
+                    input: "not an example".to_string(),
+                    answers: [None, None],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extract_part_two_blocks() {
+        let puzzle = "The answer is *`1`*.
+
+```
+a
+```
+
+## --- Part Two ---
+
+This time the answer is *`2`*.
+
+```
+b
+```
+
+Now the answer is *`3`*.";
+
+        assert_eq!(
+            extract(puzzle),
+            vec![
+                Example {
+                    input: "a".to_string(),
+                    answers: [None, None],
+                },
+                Example {
+                    input: "b".to_string(),
+                    answers: [None, Some("3".to_string())],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_to_file() {
+        let examples = extract(PUZZLE);
+
+        assert_eq!(
+            to_file(&examples[0]),
+            "--- example part_one=6 part_two=\"ABC\" ---\n12\n14\n"
+        );
+        assert_eq!(to_file(&examples[1]), "--- example ---\nnot an example\n");
+
+        let parsed = crate::examples::parse(&to_file(&examples[0]));
+        assert_eq!(parsed[0].input, "12\n14\n");
+        assert_eq!(parsed[0].answers.expected(2), Some("\"ABC\""));
+    }
+}
@@ -5,6 +5,25 @@
 use std::cmp::Ordering;
 use std::iter;
 
+pub mod amplifiers;
+pub mod asteroids;
+pub mod automaton;
+pub mod donut_maze;
+pub mod fft;
+mod grid;
+pub mod math;
+pub mod moons;
+pub mod network;
+pub mod ocr;
+pub mod orbits;
+pub mod reactions;
+pub mod search;
+pub mod shuffle;
+pub mod space_image;
+pub mod vault;
+
+pub use grid::{Direction, Grid, Point, Unit};
+
 #[derive(Clone)]
 pub struct Intcode {
     code: Vec<isize>,
@@ -14,6 +33,7 @@ pub struct Intcode {
 impl Intcode {
     pub fn from_input(input: &str) -> Self {
         let code = input
+            .trim()
             .split(",")
             .map(|element| element.parse::<isize>().unwrap())
             .chain(iter::repeat(0))
@@ -149,7 +169,7 @@ impl ParsedOpcode {
 }
 
 pub struct Input {
-    values: Vec<usize>,
+    values: Vec<isize>,
     index: usize,
 }
 
@@ -161,25 +181,53 @@ impl Input {
         }
     }
 
-    pub fn new(values: Vec<usize>) -> Self {
+    pub fn new(values: Vec<isize>) -> Self {
         Self { values, index: 0 }
     }
 
-    pub fn next(&mut self) -> usize {
-        let value = self.values[self.index];
+    pub fn try_next(&mut self) -> Option<isize> {
+        let value = self.values.get(self.index).copied()?;
         self.index += 1;
-        value
+        Some(value)
+    }
+
+    pub fn push(&mut self, value: isize) {
+        self.values.push(value);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.index == self.values.len()
     }
 }
 
+/// The reason a computer stopped running.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Interrupt {
+    Output(isize),
+    /// The program wants to read input that is not there yet, running again after providing
+    /// input resumes at the same instruction.
+    AwaitingInput,
+    Halted,
+}
+
+#[derive(Clone)]
 pub struct IntcodeComputer {
     pub code: Intcode,
     relative_base: isize,
+    halted: bool,
 }
 
 impl IntcodeComputer {
     pub fn new(code: Intcode) -> Self {
-        Self { code, relative_base: 0 }
+        Self {
+            code,
+            relative_base: 0,
+            halted: false,
+        }
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.halted
     }
 
     pub fn simulate_without_input(&mut self) -> Vec<isize> {
@@ -191,11 +239,32 @@ impl IntcodeComputer {
         let mut output = Vec::new();
 
         loop {
+            match self.run(&mut input) {
+                Interrupt::Output(value) => output.push(value),
+                Interrupt::AwaitingInput => panic!("Ran out of input"),
+                Interrupt::Halted => break,
+            }
+        }
+
+        output
+    }
+
+    /// Runs the program until it produces output, needs more input than is available or halts.
+    pub fn run(&mut self, input: &mut Input) -> Interrupt {
+        if self.halted {
+            return Interrupt::Halted;
+        }
+
+        loop {
+            let start = self.code.index;
             let value = self.code.next(ParameterMode::Immediate, self.relative_base);
             let mut opcode = ParsedOpcode::new(value);
 
             match opcode.code {
-                Opcode::Break => break,
+                Opcode::Break => {
+                    self.halted = true;
+                    return Interrupt::Halted;
+                }
                 Opcode::Add => {
                     let source_1 = self.code.next(opcode.next_mode(), self.relative_base);
                     let source_2 = self.code.next(opcode.next_mode(), self.relative_base);
@@ -212,12 +281,15 @@ impl IntcodeComputer {
                 }
                 Opcode::Input => {
                     let target = self.code.next_target(opcode.next_mode(), self.relative_base);
-                    let value = input.next();
-                    self.code.set(target as usize, value as isize);
+                    let Some(value) = input.try_next() else {
+                        self.code.jump_to(start);
+                        return Interrupt::AwaitingInput;
+                    };
+                    self.code.set(target as usize, value);
                 }
                 Opcode::Output => {
                     let value = self.code.next(opcode.next_mode(), self.relative_base);
-                    output.push(value);
+                    return Interrupt::Output(value);
                 }
                 Opcode::JumpIfTrue => {
                     let value = self.code.next(opcode.next_mode(), self.relative_base);
@@ -271,8 +343,6 @@ impl IntcodeComputer {
                 }
             }
         }
-
-        output
     }
 }
 
@@ -351,4 +421,18 @@ mod tests {
 
         assert_eq!(output, vec![1125899906842624])
     }
+
+    #[test]
+    fn test_run_awaits_input() {
+        let intcode = Intcode::from_input("3,9,1001,9,1,9,4,9,99,0");
+        let mut computer = IntcodeComputer::new(intcode);
+        let mut input = Input::none();
+
+        assert_eq!(computer.run(&mut input), Interrupt::AwaitingInput);
+
+        input.push(41);
+        assert_eq!(computer.run(&mut input), Interrupt::Output(42));
+        assert_eq!(computer.run(&mut input), Interrupt::Halted);
+        assert!(computer.is_halted());
+    }
 }
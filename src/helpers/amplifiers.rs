@@ -0,0 +1,126 @@
+use crate::helpers::{Input, Intcode, IntcodeComputer, Interrupt};
+use itertools::Itertools;
+
+/// A row of amplifiers that all run a copy of the same program, each one first reading its phase
+/// setting and then the signals of the amplifier before it.
+pub struct Amplifiers {
+    program: Intcode,
+}
+
+impl Amplifiers {
+    pub fn new(program: Intcode) -> Self {
+        Self { program }
+    }
+
+    fn boot(&self, phases: &[isize]) -> Vec<(IntcodeComputer, Input)> {
+        phases
+            .iter()
+            .map(|phase| {
+                (
+                    IntcodeComputer::new(self.program.clone()),
+                    Input::new(vec![*phase]),
+                )
+            })
+            .collect()
+    }
+
+    /// The signal that comes out of the last amplifier when a signal of `0` is sent into the
+    /// first one and every amplifier passes on its first output.
+    pub fn run_chain(&self, phases: &[isize]) -> isize {
+        self.boot(phases).into_iter().enumerate().fold(
+            0,
+            |signal, (index, (mut computer, mut input))| {
+                input.push(signal);
+
+                match computer.run(&mut input) {
+                    Interrupt::Output(value) => value,
+                    interrupt => {
+                        panic!("Amplifier {index} stopped without a signal: {interrupt:?}")
+                    }
+                }
+            },
+        )
+    }
+
+    /// The last signal of the last amplifier when its output is fed back into the first one,
+    /// resuming every amplifier in turn until the last one halts. Without amplifiers the signal of
+    /// `0` comes straight back, as it does from [`run_chain`](Amplifiers::run_chain).
+    pub fn run_feedback_loop(&self, phases: &[isize]) -> isize {
+        let mut amplifiers = self.boot(phases);
+        let mut signal = 0;
+        let Some(last) = amplifiers.len().checked_sub(1) else {
+            return signal;
+        };
+
+        loop {
+            for (index, (computer, input)) in amplifiers.iter_mut().enumerate() {
+                input.push(signal);
+
+                match computer.run(input) {
+                    Interrupt::Output(value) => signal = value,
+                    Interrupt::Halted if index == last => return signal,
+                    Interrupt::Halted => {}
+                    Interrupt::AwaitingInput => {
+                        panic!("Amplifier {index} is waiting for input without sending a signal")
+                    }
+                }
+            }
+        }
+    }
+
+    /// The order of the phase settings that gives the highest signal, and that signal.
+    pub fn best_phases(
+        &self,
+        phases: &[isize],
+        run: impl Fn(&Self, &[isize]) -> isize,
+    ) -> Option<(Vec<isize>, isize)> {
+        phases
+            .iter()
+            .copied()
+            .permutations(phases.len())
+            .map(|order| {
+                let signal = run(self, &order);
+                (order, signal)
+            })
+            .max_by_key(|(_, signal)| *signal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chain() {
+        let amplifiers = Amplifiers::new(Intcode::from_input(
+            "3,15,3,16,1002,16,10,16,1,16,15,15,4,15,99,0,0",
+        ));
+
+        assert_eq!(amplifiers.run_chain(&[4, 3, 2, 1, 0]), 43210);
+        assert_eq!(
+            amplifiers.best_phases(&[0, 1, 2, 3, 4], Amplifiers::run_chain),
+            Some((vec![4, 3, 2, 1, 0], 43210))
+        );
+    }
+
+    #[test]
+    fn test_feedback_loop() {
+        let amplifiers = Amplifiers::new(Intcode::from_input(
+            "3,26,1001,26,-4,26,3,27,1002,27,2,27,1,27,26,27,4,27,1001,28,-1,28,1005,28,6,99,0,0,5",
+        ));
+
+        assert_eq!(amplifiers.run_feedback_loop(&[9, 8, 7, 6, 5]), 139629729);
+        assert_eq!(
+            amplifiers.best_phases(&[5, 6, 7, 8, 9], Amplifiers::run_feedback_loop),
+            Some((vec![9, 8, 7, 6, 5], 139629729))
+        );
+    }
+
+    #[test]
+    fn test_without_amplifiers() {
+        let amplifiers = Amplifiers::new(Intcode::from_input("3,0,4,0,99"));
+
+        assert_eq!(amplifiers.run_chain(&[]), 0);
+        assert_eq!(amplifiers.run_feedback_loop(&[]), 0);
+    }
+}
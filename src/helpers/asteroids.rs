@@ -0,0 +1,196 @@
+use crate::helpers::math::gcd;
+use crate::helpers::{Grid, Point, Unit};
+use std::cmp::Ordering;
+use std::collections::{HashMap, VecDeque};
+
+/// The direction from `from` to `to` as the smallest integer step along that line, such that
+/// asteroids in the same direction map to the same step.
+pub fn direction(from: Point, to: Point) -> Point {
+    let delta = to - from;
+    let divisor = gcd(delta.x as i64, delta.y as i64).max(1) as Unit;
+
+    Point::new(delta.x / divisor, delta.y / divisor)
+}
+
+/// Orders directions clockwise starting from straight up, where `y` grows downwards. Only uses
+/// integer arithmetic so directions that are equal compare as equal.
+pub fn clockwise(a: &Point, b: &Point) -> Ordering {
+    // Directions pointing up or to the right come before those pointing down or to the left.
+    let half = |point: &Point| !(point.x > 0 || (point.x == 0 && point.y < 0));
+
+    half(a)
+        .cmp(&half(b))
+        .then_with(|| (b.x * a.y).cmp(&(a.x * b.y)))
+}
+
+pub struct AsteroidField {
+    asteroids: Vec<Point>,
+}
+
+impl AsteroidField {
+    pub fn from_input(input: &str) -> Self {
+        let grid = Grid::parse_sparse(input, |value| (value == '#').then_some(()));
+        let mut asteroids = grid.iter().map(|(point, _)| point).collect::<Vec<_>>();
+        asteroids.sort_by_key(|point| (point.y, point.x));
+
+        Self { asteroids }
+    }
+
+    pub fn asteroids(&self) -> &[Point] {
+        &self.asteroids
+    }
+
+    /// The other asteroids grouped by their direction from `station`, closest asteroid first.
+    fn lines_of_sight(&self, station: Point) -> HashMap<Point, Vec<Point>> {
+        let mut lines: HashMap<Point, Vec<Point>> = HashMap::new();
+        for asteroid in self
+            .asteroids
+            .iter()
+            .filter(|asteroid| **asteroid != station)
+        {
+            lines
+                .entry(direction(station, *asteroid))
+                .or_default()
+                .push(*asteroid);
+        }
+
+        for line in lines.values_mut() {
+            line.sort_by_key(|asteroid| asteroid.manhattan_distance(&station));
+        }
+
+        lines
+    }
+
+    /// The number of asteroids that can be seen from `station` without another one blocking it.
+    pub fn visible_from(&self, station: Point) -> usize {
+        self.lines_of_sight(station).len()
+    }
+
+    /// The asteroid from which the most other asteroids are visible, and that number.
+    pub fn best_station(&self) -> Option<(Point, usize)> {
+        self.asteroids
+            .iter()
+            .map(|station| (*station, self.visible_from(*station)))
+            .max_by_key(|(_, visible)| *visible)
+    }
+
+    /// The asteroids in the order in which a laser at `station` that starts pointing up and
+    /// rotates clockwise vaporizes them, hitting only the closest asteroid per direction on each
+    /// rotation.
+    pub fn vaporization_order(&self, station: Point) -> Vaporization {
+        let mut lines = self.lines_of_sight(station).into_iter().collect::<Vec<_>>();
+        lines.sort_by(|(a, _), (b, _)| clockwise(a, b));
+
+        Vaporization {
+            lines: lines
+                .into_iter()
+                .map(|(_, asteroids)| VecDeque::from(asteroids))
+                .collect(),
+            index: 0,
+        }
+    }
+}
+
+pub struct Vaporization {
+    lines: Vec<VecDeque<Point>>,
+    index: usize,
+}
+
+impl Iterator for Vaporization {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Point> {
+        if self.lines.is_empty() {
+            return None;
+        }
+
+        self.index %= self.lines.len();
+        let asteroid = self.lines[self.index].pop_front();
+
+        if self.lines[self.index].is_empty() {
+            self.lines.remove(self.index);
+        } else {
+            self.index += 1;
+        }
+
+        asteroid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_direction_ordering() {
+        assert_eq!(
+            direction(Point::new(1, 1), Point::new(5, -7)),
+            Point::new(1, -2)
+        );
+
+        let mut directions = [
+            Point::new(-1, -1),
+            Point::new(0, 1),
+            Point::new(1, 0),
+            Point::new(-1, 0),
+            Point::new(1, -1),
+            Point::new(0, -1),
+            Point::new(1, 1),
+            Point::new(-1, 1),
+        ];
+        directions.sort_by(clockwise);
+
+        assert_eq!(
+            directions,
+            [
+                Point::new(0, -1),
+                Point::new(1, -1),
+                Point::new(1, 0),
+                Point::new(1, 1),
+                Point::new(0, 1),
+                Point::new(-1, 1),
+                Point::new(-1, 0),
+                Point::new(-1, -1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_best_station() {
+        let field = AsteroidField::from_input(".#..#\n.....\n#####\n....#\n...##");
+
+        assert_eq!(field.visible_from(Point::new(1, 0)), 7);
+        assert_eq!(field.best_station(), Some((Point::new(3, 4), 8)));
+    }
+
+    #[test]
+    fn test_vaporization_order() {
+        let field = AsteroidField::from_input(
+            ".#....#####...#..
+##...##.#####..##
+##...#...#.#####.
+..#.....#...###..
+..#.#.....#....##",
+        );
+        let order = field.vaporization_order(Point::new(8, 3));
+
+        assert_eq!(
+            order.take(9).collect::<Vec<_>>(),
+            vec![
+                Point::new(8, 1),
+                Point::new(9, 0),
+                Point::new(9, 1),
+                Point::new(10, 0),
+                Point::new(9, 2),
+                Point::new(11, 1),
+                Point::new(12, 1),
+                Point::new(11, 2),
+                Point::new(15, 1),
+            ]
+        );
+        assert_eq!(
+            field.vaporization_order(Point::new(8, 3)).count(),
+            field.asteroids().len() - 1
+        );
+    }
+}
@@ -0,0 +1,352 @@
+use crate::helpers::{Grid, Point, Unit};
+use std::collections::{HashMap, VecDeque};
+
+/// The width and height of a layout.
+pub const SIZE: usize = 5;
+const CELLS: usize = SIZE * SIZE;
+const CENTER: usize = CELLS / 2;
+/// The most neighbours a cell can have: a cell next to the center of a recursive layout touches
+/// three cells on its own level and the five cells along the edge of the level inside.
+const MAX_NEIGHBOURS: u32 = 8;
+
+/// Which cells are alive in the next step, by their number of living neighbours.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Rule {
+    birth: u16,
+    survival: u16,
+}
+
+impl Rule {
+    /// Bugs survive with exactly one adjacent bug and infest empty cells with one or two.
+    pub const BUGS: Rule = Rule {
+        birth: 0b110,
+        survival: 0b10,
+    };
+
+    /// Panics when a count is above the most neighbours a cell can have.
+    pub fn new(birth: &[u32], survival: &[u32]) -> Self {
+        let mask = |counts: &[u32]| {
+            counts.iter().fold(0, |mask, count| {
+                assert!(
+                    *count <= MAX_NEIGHBOURS,
+                    "a cell has at most {MAX_NEIGHBOURS} neighbours, not {count}"
+                );
+                mask | 1 << count
+            })
+        };
+
+        Self {
+            birth: mask(birth),
+            survival: mask(survival),
+        }
+    }
+
+    pub fn is_alive(&self, alive: bool, neighbours: u32) -> bool {
+        let mask = if alive { self.survival } else { self.birth };
+
+        mask.checked_shr(neighbours)
+            .is_some_and(|mask| mask & 1 == 1)
+    }
+}
+
+/// A 5x5 layout with one bit per cell, in reading order.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Layout(u32);
+
+fn index(point: Point) -> usize {
+    point.y as usize * SIZE + point.x as usize
+}
+
+fn point(index: usize) -> Point {
+    Point::new((index % SIZE) as Unit, (index / SIZE) as Unit)
+}
+
+fn mask(points: impl IntoIterator<Item = Point>) -> u32 {
+    points
+        .into_iter()
+        .fold(0, |mask, point| mask | 1 << index(point))
+}
+
+fn in_layout(point: &Point) -> bool {
+    (0..SIZE as Unit).contains(&point.x) && (0..SIZE as Unit).contains(&point.y)
+}
+
+impl Layout {
+    pub const EMPTY: Layout = Layout(0);
+
+    pub fn from_input(input: &str) -> Self {
+        let grid = Grid::parse(input.trim(), |value| value == '#');
+        assert!(
+            grid.width() == SIZE && grid.height() == SIZE,
+            "Layouts must be {SIZE}x{SIZE}"
+        );
+
+        Self(mask(
+            grid.iter()
+                .filter(|(_, alive)| **alive)
+                .map(|(point, _)| point),
+        ))
+    }
+
+    pub fn bits(&self) -> u32 {
+        self.0
+    }
+
+    pub fn is_alive(&self, point: Point) -> bool {
+        in_layout(&point) && (self.0 >> index(point)) & 1 == 1
+    }
+
+    pub fn count(&self) -> u32 {
+        self.0.count_ones()
+    }
+
+    /// Every living cell counts as the power of two of its index in reading order.
+    pub fn biodiversity(&self) -> u32 {
+        self.0
+    }
+
+    pub fn render(&self) -> String {
+        self.render_with_center(None)
+    }
+
+    fn render_with_center(&self, center: Option<char>) -> String {
+        (0..SIZE)
+            .map(|y| {
+                (0..SIZE)
+                    .map(|x| match (y * SIZE + x, center) {
+                        (CENTER, Some(center)) => center,
+                        (index, _) if (self.0 >> index) & 1 == 1 => '#',
+                        _ => '.',
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// The first layout that appears twice, along with the step it first appeared at and after how
+/// many steps it reappears.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    pub layout: Layout,
+    pub start: usize,
+    pub length: usize,
+}
+
+/// A stack of layouts where the center cell of every level holds the next level. Depth `0` is the
+/// initial level, negative depths surround it and positive depths lie within it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecursiveLayout {
+    levels: VecDeque<Layout>,
+    outermost: i32,
+}
+
+impl RecursiveLayout {
+    pub fn new(layout: Layout) -> Self {
+        Self {
+            levels: VecDeque::from([Layout(layout.0 & !(1 << CENTER))]),
+            outermost: 0,
+        }
+    }
+
+    /// The depths of the levels that may contain living cells.
+    pub fn depths(&self) -> std::ops::Range<i32> {
+        self.outermost..self.outermost + self.levels.len() as i32
+    }
+
+    pub fn level(&self, depth: i32) -> Layout {
+        usize::try_from(depth - self.outermost)
+            .ok()
+            .and_then(|index| self.levels.get(index).copied())
+            .unwrap_or(Layout::EMPTY)
+    }
+
+    pub fn count(&self) -> u32 {
+        self.levels.iter().map(Layout::count).sum()
+    }
+
+    /// The level at `depth`, showing the center cell that holds the next level as `?`.
+    pub fn render_level(&self, depth: i32) -> String {
+        self.level(depth).render_with_center(Some('?'))
+    }
+}
+
+pub struct Automaton {
+    rule: Rule,
+    /// The adjacent cells of every cell within the same layout.
+    adjacent: [u32; CELLS],
+    /// The adjacent cells of every cell in the level that surrounds it.
+    outer: [u32; CELLS],
+    /// The adjacent cells of every cell in the level that it surrounds.
+    inner: [u32; CELLS],
+}
+
+impl Automaton {
+    pub fn new(rule: Rule) -> Self {
+        let center = point(CENTER);
+
+        let mut outer = [0; CELLS];
+        let mut inner = [0; CELLS];
+
+        for (index, cell) in (0..CELLS).map(|index| (index, point(index))) {
+            for neighbour in cell.neighbours() {
+                if !in_layout(&neighbour) {
+                    outer[index] |= mask([center + (neighbour - cell)]);
+                } else if neighbour == center {
+                    // The cells on the edge of the inner level that faces this cell.
+                    let side = cell - center;
+                    inner[index] = mask(
+                        (0..CELLS)
+                            .map(point)
+                            .filter(|inner| !in_layout(&(*inner + side))),
+                    );
+                }
+            }
+        }
+
+        Self {
+            rule,
+            adjacent: std::array::from_fn(|index| {
+                mask(point(index).neighbours().filter(in_layout))
+            }),
+            outer,
+            inner,
+        }
+    }
+
+    pub fn step(&self, layout: Layout) -> Layout {
+        Layout((0..CELLS).fold(0, |next, index| {
+            let alive = (layout.0 >> index) & 1 == 1;
+            let neighbours = (layout.0 & self.adjacent[index]).count_ones();
+
+            match self.rule.is_alive(alive, neighbours) {
+                true => next | 1 << index,
+                false => next,
+            }
+        }))
+    }
+
+    /// Steps until a layout appears for the second time.
+    pub fn find_cycle(&self, layout: Layout) -> Cycle {
+        let mut seen = HashMap::new();
+        let mut layout = layout;
+
+        for step in 0.. {
+            if let Some(start) = seen.insert(layout, step) {
+                return Cycle {
+                    layout,
+                    start,
+                    length: step - start,
+                };
+            }
+
+            layout = self.step(layout);
+        }
+
+        unreachable!("There are only finitely many layouts")
+    }
+
+    fn step_level(&self, outer: Layout, level: Layout, inner: Layout) -> Layout {
+        Layout(
+            (0..CELLS)
+                .filter(|index| *index != CENTER)
+                .fold(0, |next, index| {
+                    let alive = (level.0 >> index) & 1 == 1;
+                    let neighbours = (level.0 & self.adjacent[index] & !(1 << CENTER)).count_ones()
+                        + (outer.0 & self.outer[index]).count_ones()
+                        + (inner.0 & self.inner[index]).count_ones();
+
+                    match self.rule.is_alive(alive, neighbours) {
+                        true => next | 1 << index,
+                        false => next,
+                    }
+                }),
+        )
+    }
+
+    /// Steps every level of a recursive layout at once, growing it by a level on either side
+    /// whenever life spreads there.
+    pub fn step_recursive(&self, layout: &RecursiveLayout) -> RecursiveLayout {
+        let depths = layout.depths();
+        let mut levels = (depths.start - 1..depths.end + 1)
+            .map(|depth| {
+                self.step_level(
+                    layout.level(depth - 1),
+                    layout.level(depth),
+                    layout.level(depth + 1),
+                )
+            })
+            .collect::<VecDeque<_>>();
+        let mut outermost = depths.start - 1;
+
+        while levels.len() > 1 && levels.front() == Some(&Layout::EMPTY) {
+            levels.pop_front();
+            outermost += 1;
+        }
+
+        while levels.len() > 1 && levels.back() == Some(&Layout::EMPTY) {
+            levels.pop_back();
+        }
+
+        RecursiveLayout { levels, outermost }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LAYOUT: &str = "....#
+#..#.
+#..##
+..#..
+#....";
+
+    #[test]
+    fn test_step() {
+        let automaton = Automaton::new(Rule::BUGS);
+        let layout = automaton.step(Layout::from_input(LAYOUT));
+
+        assert_eq!(layout.render(), "#..#.\n####.\n###.#\n##.##\n.##..");
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        let automaton = Automaton::new(Rule::BUGS);
+        let cycle = automaton.find_cycle(Layout::from_input(LAYOUT));
+
+        assert_eq!(cycle.layout.biodiversity(), 2129920);
+    }
+
+    #[test]
+    fn test_rule() {
+        let rule = Rule::new(&[3], &[2, 3]);
+
+        assert!(rule.is_alive(false, 3));
+        assert!(!rule.is_alive(false, 2));
+        assert!(rule.is_alive(true, 2));
+        assert!(!rule.is_alive(true, 4));
+        assert!(!rule.is_alive(true, 16));
+    }
+
+    #[test]
+    #[should_panic(expected = "a cell has at most 8 neighbours, not 16")]
+    fn test_rule_too_many_neighbours() {
+        Rule::new(&[3], &[16]);
+    }
+
+    #[test]
+    fn test_recursive() {
+        let automaton = Automaton::new(Rule::BUGS);
+        let layout = (0..10).fold(
+            RecursiveLayout::new(Layout::from_input(LAYOUT)),
+            |layout, _| automaton.step_recursive(&layout),
+        );
+
+        assert_eq!(layout.count(), 99);
+        assert_eq!(layout.depths(), -5..6);
+        assert_eq!(layout.render_level(0), ".#...\n.#.##\n.#?..\n.....\n.....");
+        assert_eq!(layout.render_level(-5), "..#..\n.#.#.\n..?.#\n.#.#.\n..#..");
+    }
+}
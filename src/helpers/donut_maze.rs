@@ -0,0 +1,297 @@
+use crate::helpers::search::{self, Cost};
+use crate::helpers::{Direction, Grid, Point};
+
+const ENTRANCE: &str = "AA";
+const EXIT: &str = "ZZ";
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Side {
+    Inner,
+    Outer,
+}
+
+/// One end of a portal: the open tile next to its label.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Portal {
+    pub label: String,
+    pub position: Point,
+    pub side: Side,
+}
+
+/// A portal visited on a route and the level of recursion it was visited at.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Waypoint {
+    pub label: String,
+    pub side: Side,
+    pub level: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Route {
+    pub steps: Cost,
+    pub waypoints: Vec<Waypoint>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct State {
+    portal: usize,
+    level: usize,
+}
+
+pub struct DonutMaze {
+    portals: Vec<Portal>,
+    /// For every portal, the other portals that can be walked to and the distance to them.
+    paths: Vec<Vec<(usize, Cost)>>,
+    /// For every portal, the portal with the same label on the other side of the maze.
+    partners: Vec<Option<usize>>,
+}
+
+impl DonutMaze {
+    pub fn from_input(input: &str) -> Self {
+        let grid = Grid::parse_sparse(input, |value| (value != ' ').then_some(value));
+        let portals = Self::detect_portals(&grid);
+
+        let paths = portals
+            .iter()
+            .map(|portal| {
+                let search = search::bfs(portal.position, |point| {
+                    grid.neighbours(*point)
+                        .filter(|(_, value)| **value == '.')
+                        .map(|(neighbour, _)| neighbour)
+                        .collect::<Vec<_>>()
+                });
+
+                portals
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, other)| other.position != portal.position)
+                    .filter_map(|(index, other)| {
+                        search
+                            .distance(&other.position)
+                            .map(|distance| (index, distance))
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let partners = portals
+            .iter()
+            .map(|portal| {
+                portals.iter().position(|other| {
+                    other.label == portal.label && other.position != portal.position
+                })
+            })
+            .collect();
+
+        Self {
+            portals,
+            paths,
+            partners,
+        }
+    }
+
+    /// Finds every pair of letters next to an open tile. Portals on the edge of the area covered
+    /// by the maze are on the outer side of the donut, all others are on the inner side.
+    fn detect_portals(grid: &Grid<char>) -> Vec<Portal> {
+        let tiles = grid
+            .iter()
+            .filter(|(_, value)| matches!(value, '#' | '.'))
+            .map(|(point, _)| point)
+            .collect::<Vec<_>>();
+        let min = Point::new(
+            tiles.iter().map(|point| point.x).min().unwrap_or(0),
+            tiles.iter().map(|point| point.y).min().unwrap_or(0),
+        );
+        let max = Point::new(
+            tiles.iter().map(|point| point.x).max().unwrap_or(0),
+            tiles.iter().map(|point| point.y).max().unwrap_or(0),
+        );
+
+        let mut portals = grid
+            .iter()
+            .filter(|(_, value)| value.is_ascii_uppercase())
+            .flat_map(|(first, value)| {
+                [Direction::Right, Direction::Down]
+                    .into_iter()
+                    .filter_map(move |direction| {
+                        let second = first.step(direction);
+                        let other = grid
+                            .get(&second)
+                            .filter(|other| other.is_ascii_uppercase())?;
+
+                        let position = [first.step(direction.reverse()), second.step(direction)]
+                            .into_iter()
+                            .find(|point| grid.get(point) == Some(&'.'))?;
+
+                        let side = match position.x == min.x
+                            || position.x == max.x
+                            || position.y == min.y
+                            || position.y == max.y
+                        {
+                            true => Side::Outer,
+                            false => Side::Inner,
+                        };
+
+                        Some(Portal {
+                            label: format!("{value}{other}"),
+                            position,
+                            side,
+                        })
+                    })
+            })
+            .collect::<Vec<_>>();
+
+        portals.sort_by(|a, b| (&a.label, a.position).cmp(&(&b.label, b.position)));
+        portals
+    }
+
+    pub fn portals(&self) -> &[Portal] {
+        &self.portals
+    }
+
+    fn find(&self, label: &str) -> Option<usize> {
+        self.portals.iter().position(|portal| portal.label == label)
+    }
+
+    /// The shortest route from `AA` to `ZZ` when every portal connects two tiles of the same maze.
+    pub fn shortest_route(&self) -> Option<Route> {
+        self.solve(|_, level| Some(level))
+    }
+
+    /// The shortest route from `AA` to `ZZ` when inner portals lead one level deeper into a copy
+    /// of the maze and outer portals lead one level back up. Levels deeper than `max_depth` are
+    /// never entered.
+    pub fn shortest_recursive_route(&self, max_depth: usize) -> Option<Route> {
+        self.solve(|side, level| match side {
+            Side::Inner => Some(level + 1).filter(|level| *level <= max_depth),
+            Side::Outer => level.checked_sub(1),
+        })
+    }
+
+    /// Finds the shortest route where `traverse` gives the level reached by taking a portal on the
+    /// given side at the given level, or `None` when the portal cannot be taken.
+    fn solve(&self, traverse: impl Fn(Side, usize) -> Option<usize>) -> Option<Route> {
+        let entrance = self.find(ENTRANCE)?;
+        let exit = self.find(EXIT)?;
+
+        let start = State {
+            portal: entrance,
+            level: 0,
+        };
+
+        let (steps, states) = search::shortest_path(
+            start,
+            |state| state.portal == exit && state.level == 0,
+            |state| {
+                let walk = self.paths[state.portal].iter().map(|(portal, distance)| {
+                    let state = State {
+                        portal: *portal,
+                        level: state.level,
+                    };
+
+                    (state, *distance)
+                });
+
+                let warp = self.partners[state.portal].and_then(|partner| {
+                    let level = traverse(self.portals[state.portal].side, state.level)?;
+                    Some((
+                        State {
+                            portal: partner,
+                            level,
+                        },
+                        1,
+                    ))
+                });
+
+                walk.chain(warp).collect::<Vec<_>>()
+            },
+        )?;
+
+        let waypoints = states
+            .into_iter()
+            .map(|state| {
+                let portal = &self.portals[state.portal];
+                Waypoint {
+                    label: portal.label.clone(),
+                    side: portal.side,
+                    level: state.level,
+                }
+            })
+            .collect();
+
+        Some(Route { steps, waypoints })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAZE: &str = "         A
+         A
+  #######.#########
+  #######.........#
+  #######.#######.#
+  #######.#######.#
+  #######.#######.#
+  #####  B    ###.#
+BC...##  C    ###.#
+  ##.##       ###.#
+  ##...DE  F  ###.#
+  #####    G  ###.#
+  #########.#####.#
+DE..#######...###.#
+  #.#########.###.#
+FG..#########.....#
+  ###########.#####
+             Z
+             Z       ";
+
+    #[test]
+    fn test_detect_portals() {
+        let maze = DonutMaze::from_input(MAZE);
+        let portals = maze
+            .portals()
+            .iter()
+            .map(|portal| (portal.label.as_str(), portal.side))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            portals,
+            vec![
+                ("AA", Side::Outer),
+                ("BC", Side::Outer),
+                ("BC", Side::Inner),
+                ("DE", Side::Outer),
+                ("DE", Side::Inner),
+                ("FG", Side::Outer),
+                ("FG", Side::Inner),
+                ("ZZ", Side::Outer),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_shortest_route() {
+        let maze = DonutMaze::from_input(MAZE);
+        let route = maze.shortest_route().unwrap();
+
+        assert_eq!(route.steps, 23);
+        assert_eq!(
+            route
+                .waypoints
+                .iter()
+                .map(|waypoint| waypoint.label.as_str())
+                .collect::<Vec<_>>(),
+            vec!["AA", "BC", "BC", "DE", "DE", "FG", "FG", "ZZ"]
+        );
+    }
+
+    #[test]
+    fn test_shortest_recursive_route() {
+        let maze = DonutMaze::from_input(MAZE);
+
+        assert_eq!(maze.shortest_recursive_route(0).unwrap().steps, 26);
+        assert_eq!(maze.shortest_recursive_route(10).unwrap().steps, 26);
+    }
+}
@@ -0,0 +1,154 @@
+const BASE_PATTERN: [i64; 4] = [0, 1, 0, -1];
+
+pub fn parse_signal(input: &str) -> Vec<u8> {
+    input
+        .trim()
+        .chars()
+        .map(|value| value.to_digit(10).expect("signal must only contain digits") as u8)
+        .collect()
+}
+
+/// The pattern value that input digit `input` is multiplied with to compute output digit
+/// `output`: every base pattern value repeated `output + 1` times, skipping the very first value.
+pub fn pattern_value(output: usize, input: usize) -> i64 {
+    BASE_PATTERN[((input + 1) / (output + 1)) % BASE_PATTERN.len()]
+}
+
+/// Applies one phase by multiplying every digit with its pattern value, which takes quadratic
+/// time in the length of the signal.
+pub fn phase(signal: &[u8]) -> Vec<u8> {
+    (0..signal.len())
+        .map(|output| {
+            let sum = signal
+                .iter()
+                .enumerate()
+                .skip(output)
+                .map(|(input, digit)| *digit as i64 * pattern_value(output, input))
+                .sum::<i64>();
+
+            (sum.abs() % 10) as u8
+        })
+        .collect()
+}
+
+/// Applies one phase to the digits of a signal starting at an offset in its second half. The
+/// pattern there is zero before a digit and one from that digit on, so every output digit is the
+/// sum of all digits after it.
+fn suffix_phase(tail: &mut [u8]) {
+    let mut sum = 0;
+
+    for digit in tail.iter_mut().rev() {
+        sum = (sum + *digit) % 10;
+        *digit = sum;
+    }
+}
+
+/// Applies `phases` phases to the complete signal.
+pub fn transform(signal: &[u8], phases: usize) -> Vec<u8> {
+    (0..phases).fold(signal.to_vec(), |signal, _| phase(&signal))
+}
+
+fn direct_message(signal: &[u8], phases: usize, offset: usize, length: usize) -> Vec<u8> {
+    transform(signal, phases)[offset..offset + length].to_vec()
+}
+
+fn suffix_message(signal: &[u8], phases: usize, offset: usize, length: usize) -> Vec<u8> {
+    let mut tail = signal[offset..].to_vec();
+
+    for _ in 0..phases {
+        suffix_phase(&mut tail);
+    }
+
+    tail[..length].to_vec()
+}
+
+/// The `length` digits at `offset` after applying `phases` phases to the signal repeated `repeat`
+/// times. Uses the linear time suffix sums when the offset lies in the second half of the signal
+/// and the direct transform otherwise.
+pub fn message(
+    signal: &[u8],
+    repeat: usize,
+    phases: usize,
+    offset: usize,
+    length: usize,
+) -> Vec<u8> {
+    let total = signal.len() * repeat;
+    assert!(
+        offset + length <= total,
+        "message must lie within the signal"
+    );
+
+    if offset >= total / 2 {
+        let tail = signal
+            .iter()
+            .cycle()
+            .skip(offset)
+            .take(total - offset)
+            .copied()
+            .collect::<Vec<_>>();
+
+        return suffix_message(&tail, phases, 0, length);
+    }
+
+    direct_message(&signal.repeat(repeat), phases, offset, length)
+}
+
+/// Formats digits as a number, keeping leading zeros.
+pub fn digits_to_string(digits: &[u8]) -> String {
+    digits.iter().map(|digit| (b'0' + digit) as char).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SIGNALS: [&str; 3] = [
+        "80871224585914546619083218645595",
+        "19617804207202209144916044189917",
+        "69317163492948606335995924319873",
+    ];
+
+    #[test]
+    fn test_pattern() {
+        let row = (0..8)
+            .map(|input| pattern_value(1, input))
+            .collect::<Vec<_>>();
+        assert_eq!(row, vec![0, 1, 1, 0, 0, -1, -1, 0]);
+    }
+
+    #[test]
+    fn test_transform() {
+        let signal = parse_signal("12345678");
+
+        assert_eq!(digits_to_string(&transform(&signal, 1)), "48226158");
+        assert_eq!(digits_to_string(&transform(&signal, 4)), "01029498");
+    }
+
+    #[test]
+    fn test_message() {
+        let expected = ["24176176", "73745418", "52432133"];
+
+        for (signal, expected) in SIGNALS.iter().zip(expected) {
+            let signal = parse_signal(signal);
+            assert_eq!(digits_to_string(&message(&signal, 1, 100, 0, 8)), expected);
+        }
+    }
+
+    #[test]
+    fn test_suffix_sums_agree_with_direct_transform() {
+        for signal in SIGNALS {
+            let signal = parse_signal(signal).repeat(3);
+            let half = signal.len() / 2;
+
+            for offset in half..signal.len() {
+                let length = signal.len() - offset;
+
+                assert_eq!(
+                    suffix_message(&signal, 10, offset, length),
+                    direct_message(&signal, 10, offset, length),
+                    "offset {offset}"
+                );
+            }
+        }
+    }
+}
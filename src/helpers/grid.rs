@@ -0,0 +1,550 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+pub type Unit = isize;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: Unit,
+    pub y: Unit,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub const fn new(x: Unit, y: Unit) -> Self {
+        Self { x, y }
+    }
+
+    pub fn manhattan_distance(&self, other: &Point) -> Unit {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    pub fn step(self, direction: Direction) -> Self {
+        self + direction.delta()
+    }
+
+    /// The four orthogonally adjacent points, in the order of `Direction::ALL`.
+    pub fn neighbours(self) -> impl Iterator<Item = Point> {
+        Direction::ALL
+            .into_iter()
+            .map(move |direction| self.step(direction))
+    }
+
+    /// The eight adjacent points including diagonals, clockwise starting from the top.
+    pub fn neighbours_with_diagonals(self) -> impl Iterator<Item = Point> {
+        [
+            (0, -1),
+            (1, -1),
+            (1, 0),
+            (1, 1),
+            (0, 1),
+            (-1, 1),
+            (-1, 0),
+            (-1, -1),
+        ]
+        .into_iter()
+        .map(move |(x, y)| self + Point::new(x, y))
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<Unit> for Point {
+    type Output = Point;
+
+    fn mul(self, factor: Unit) -> Point {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+/// Directions on a grid where `y` grows downwards, as it does when reading a character map.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn from_char(value: char) -> Option<Self> {
+        match value {
+            'U' | '^' => Some(Direction::Up),
+            'R' | '>' => Some(Direction::Right),
+            'D' | 'v' => Some(Direction::Down),
+            'L' | '<' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    pub fn delta(&self) -> Point {
+        match self {
+            Direction::Up => Point::new(0, -1),
+            Direction::Right => Point::new(1, 0),
+            Direction::Down => Point::new(0, 1),
+            Direction::Left => Point::new(-1, 0),
+        }
+    }
+
+    pub fn turn_right(&self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn turn_left(&self) -> Self {
+        self.reverse().turn_right()
+    }
+
+    pub fn reverse(&self) -> Self {
+        self.turn_right().turn_right()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Storage<T> {
+    Bounded {
+        width: usize,
+        height: usize,
+        cells: Vec<T>,
+    },
+    Unbounded(HashMap<Point, T>),
+}
+
+/// A two dimensional grid of cells.
+///
+/// A bounded grid stores every cell of a `width` by `height` rectangle starting at the origin,
+/// an unbounded grid only stores the cells that have been set and can grow in any direction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    storage: Storage<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn unbounded() -> Self {
+        Self {
+            storage: Storage::Unbounded(HashMap::new()),
+        }
+    }
+
+    /// Parses a character map into a bounded grid, mapping every character with `convert`.
+    pub fn parse(input: &str, convert: impl Fn(char) -> T) -> Self {
+        let rows = input
+            .lines()
+            .map(|line| line.chars().map(&convert).collect::<Vec<T>>())
+            .collect::<Vec<Vec<T>>>();
+
+        let width = rows.first().map_or(0, |row| row.len());
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "All rows of a bounded grid must have the same width"
+        );
+
+        Self {
+            storage: Storage::Bounded {
+                width,
+                height: rows.len(),
+                cells: rows.into_iter().flatten().collect(),
+            },
+        }
+    }
+
+    /// Parses a character map into an unbounded grid, only storing the characters for which
+    /// `convert` returns a value. Rows are allowed to have different lengths.
+    pub fn parse_sparse(input: &str, convert: impl Fn(char) -> Option<T>) -> Self {
+        let cells = input
+            .lines()
+            .enumerate()
+            .flat_map(|(y, line)| {
+                line.chars()
+                    .enumerate()
+                    .filter_map(|(x, value)| {
+                        convert(value).map(|value| (Point::new(x as Unit, y as Unit), value))
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        Self {
+            storage: Storage::Unbounded(cells),
+        }
+    }
+
+    pub fn is_bounded(&self) -> bool {
+        matches!(self.storage, Storage::Bounded { .. })
+    }
+
+    /// The smallest and largest point (inclusive) of the area covered by the grid, or `None` when
+    /// the grid is empty.
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        match &self.storage {
+            Storage::Bounded { width, height, .. } => {
+                if *width == 0 || *height == 0 {
+                    return None;
+                }
+
+                Some((
+                    Point::ORIGIN,
+                    Point::new(*width as Unit - 1, *height as Unit - 1),
+                ))
+            }
+            Storage::Unbounded(cells) => cells.keys().fold(None, |bounds, point| match bounds {
+                None => Some((*point, *point)),
+                Some((min, max)) => Some((
+                    Point::new(min.x.min(point.x), min.y.min(point.y)),
+                    Point::new(max.x.max(point.x), max.y.max(point.y)),
+                )),
+            }),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.bounds()
+            .map_or(0, |(min, max)| (max.x - min.x + 1) as usize)
+    }
+
+    pub fn height(&self) -> usize {
+        self.bounds()
+            .map_or(0, |(min, max)| (max.y - min.y + 1) as usize)
+    }
+
+    fn index_of(&self, point: &Point) -> Option<usize> {
+        match &self.storage {
+            Storage::Bounded { width, height, .. } => {
+                if point.x < 0
+                    || point.y < 0
+                    || point.x >= *width as Unit
+                    || point.y >= *height as Unit
+                {
+                    return None;
+                }
+
+                Some(point.y as usize * width + point.x as usize)
+            }
+            Storage::Unbounded(_) => None,
+        }
+    }
+
+    pub fn contains(&self, point: &Point) -> bool {
+        self.get(point).is_some()
+    }
+
+    pub fn get(&self, point: &Point) -> Option<&T> {
+        match &self.storage {
+            Storage::Bounded { cells, .. } => self.index_of(point).map(|index| &cells[index]),
+            Storage::Unbounded(cells) => cells.get(point),
+        }
+    }
+
+    pub fn get_mut(&mut self, point: &Point) -> Option<&mut T> {
+        let index = self.index_of(point);
+
+        match &mut self.storage {
+            Storage::Bounded { cells, .. } => index.map(|index| &mut cells[index]),
+            Storage::Unbounded(cells) => cells.get_mut(point),
+        }
+    }
+
+    /// Sets the value of a cell. Panics when the point lies outside of a bounded grid.
+    pub fn set(&mut self, point: Point, value: T) {
+        let index = self.index_of(&point);
+
+        match &mut self.storage {
+            Storage::Bounded { cells, .. } => {
+                let index = index.unwrap_or_else(|| panic!("{point:?} is outside of the grid"));
+                cells[index] = value;
+            }
+            Storage::Unbounded(cells) => {
+                cells.insert(point, value);
+            }
+        }
+    }
+
+    /// Iterates over all stored cells. Bounded grids are iterated row by row.
+    pub fn iter(&self) -> Box<dyn Iterator<Item = (Point, &T)> + '_> {
+        match &self.storage {
+            Storage::Bounded { width, cells, .. } => {
+                let width = *width;
+                Box::new(cells.iter().enumerate().map(move |(index, value)| {
+                    (
+                        Point::new((index % width) as Unit, (index / width) as Unit),
+                        value,
+                    )
+                }))
+            }
+            Storage::Unbounded(cells) => {
+                Box::new(cells.iter().map(|(point, value)| (*point, value)))
+            }
+        }
+    }
+
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<Point> {
+        self.iter()
+            .find(|(_, value)| predicate(value))
+            .map(|(point, _)| point)
+    }
+
+    /// The orthogonal neighbours of `point` that are present in the grid.
+    pub fn neighbours(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+        point
+            .neighbours()
+            .filter_map(|neighbour| self.get(&neighbour).map(|value| (neighbour, value)))
+    }
+
+    /// The orthogonal and diagonal neighbours of `point` that are present in the grid.
+    pub fn neighbours_with_diagonals(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+        point
+            .neighbours_with_diagonals()
+            .filter_map(|neighbour| self.get(&neighbour).map(|value| (neighbour, value)))
+    }
+
+    /// Renders the area covered by the grid row by row, `render` receives `None` for points that
+    /// are not stored in an unbounded grid.
+    pub fn render(&self, render: impl Fn(Option<&T>) -> char) -> String {
+        let Some((min, max)) = self.bounds() else {
+            return String::new();
+        };
+
+        (min.y..=max.y)
+            .map(|y| {
+                (min.x..=max.x)
+                    .map(|x| render(self.get(&Point::new(x, y))))
+                    .collect::<String>()
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Self {
+            storage: Storage::Bounded {
+                width,
+                height,
+                cells: vec![value; width * height],
+            },
+        }
+    }
+
+    /// Moves every cell to a new position, given its position relative to the top left corner
+    /// of the bounds and the width and height of the grid.
+    fn transform(
+        &self,
+        transpose: bool,
+        convert: impl Fn(Unit, Unit, Unit, Unit) -> Point,
+    ) -> Self {
+        let Some((min, max)) = self.bounds() else {
+            return self.clone();
+        };
+
+        let (width, height) = (max.x - min.x + 1, max.y - min.y + 1);
+        let map = |point: Point| {
+            let relative = point - min;
+            convert(relative.x, relative.y, width, height)
+        };
+
+        match &self.storage {
+            Storage::Bounded { cells, .. } => {
+                let (width, height) = match transpose {
+                    true => (height, width),
+                    false => (width, height),
+                };
+
+                let mut transformed = vec![None; cells.len()];
+                for (point, value) in self.iter() {
+                    let target = map(point);
+                    transformed[(target.y * width + target.x) as usize] = Some(value.clone());
+                }
+
+                Self {
+                    storage: Storage::Bounded {
+                        width: width as usize,
+                        height: height as usize,
+                        cells: transformed.into_iter().map(Option::unwrap).collect(),
+                    },
+                }
+            }
+            Storage::Unbounded(cells) => Self {
+                storage: Storage::Unbounded(
+                    cells
+                        .iter()
+                        .map(|(point, value)| (map(*point) + min, value.clone()))
+                        .collect(),
+                ),
+            },
+        }
+    }
+
+    pub fn rotate_clockwise(&self) -> Self {
+        self.transform(true, |x, y, _, height| Point::new(height - 1 - y, x))
+    }
+
+    pub fn rotate_counter_clockwise(&self) -> Self {
+        self.transform(true, |x, y, width, _| Point::new(y, width - 1 - x))
+    }
+
+    /// Mirrors the grid along the vertical axis, swapping left and right.
+    pub fn flip_horizontal(&self) -> Self {
+        self.transform(false, |x, y, width, _| Point::new(width - 1 - x, y))
+    }
+
+    /// Mirrors the grid along the horizontal axis, swapping top and bottom.
+    pub fn flip_vertical(&self) -> Self {
+        self.transform(false, |x, y, _, height| Point::new(x, height - 1 - y))
+    }
+}
+
+impl<T> Default for Grid<T> {
+    fn default() -> Self {
+        Self::unbounded()
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let Some((min, max)) = self.bounds() else {
+            return Ok(());
+        };
+
+        for y in min.y..=max.y {
+            if y != min.y {
+                writeln!(f)?;
+            }
+
+            for x in min.x..=max.x {
+                match self.get(&Point::new(x, y)) {
+                    Some(value) => write!(f, "{value}")?,
+                    None => write!(f, " ")?,
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: &str = "#..\n.#.\n##.";
+
+    #[test]
+    fn test_point_arithmetic() {
+        let a = Point::new(3, -2);
+        let b = Point::new(-1, 4);
+
+        assert_eq!(a + b, Point::new(2, 2));
+        assert_eq!(a - b, Point::new(4, -6));
+        assert_eq!(a * 3, Point::new(9, -6));
+        assert_eq!(-a, Point::new(-3, 2));
+        assert_eq!(a.manhattan_distance(&b), 10);
+    }
+
+    #[test]
+    fn test_parse_and_render() {
+        let grid = Grid::parse(MAP, |value| value == '#');
+
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 3);
+        assert_eq!(grid.get(&Point::new(1, 1)), Some(&true));
+        assert_eq!(grid.get(&Point::new(3, 1)), None);
+        assert_eq!(
+            grid.render(|value| if *value.unwrap() { '#' } else { '.' }),
+            MAP
+        );
+    }
+
+    #[test]
+    fn test_sparse_grid() {
+        let mut grid = Grid::parse_sparse(MAP, |value| (value == '#').then_some(value));
+        assert_eq!(grid.iter().count(), 4);
+
+        grid.set(Point::new(-1, 0), '#');
+        assert_eq!(grid.bounds(), Some((Point::new(-1, 0), Point::new(1, 2))));
+        assert_eq!(grid.to_string(), "## \n  #\n ##");
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::parse(MAP, |value| value);
+
+        assert_eq!(grid.neighbours(Point::ORIGIN).count(), 2);
+        assert_eq!(grid.neighbours(Point::new(1, 1)).count(), 4);
+        assert_eq!(grid.neighbours_with_diagonals(Point::new(1, 1)).count(), 8);
+        assert_eq!(
+            grid.neighbours_with_diagonals(Point::new(2, 2))
+                .filter(|(_, value)| **value == '#')
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_rotate_and_flip() {
+        let grid = Grid::parse("ab\ncd\nef", |value| value);
+
+        assert_eq!(grid.rotate_clockwise().to_string(), "eca\nfdb");
+        assert_eq!(grid.rotate_counter_clockwise().to_string(), "bdf\nace");
+        assert_eq!(grid.flip_horizontal().to_string(), "ba\ndc\nfe");
+        assert_eq!(grid.flip_vertical().to_string(), "ef\ncd\nab");
+
+        let sparse = Grid::parse_sparse("ab\ncd\nef", Some);
+        assert_eq!(sparse.rotate_clockwise().to_string(), "eca\nfdb");
+    }
+
+    #[test]
+    fn test_direction_turns() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.reverse(), Direction::Right);
+        assert_eq!(Point::ORIGIN.step(Direction::Down), Point::new(0, 1));
+    }
+}
@@ -0,0 +1,113 @@
+/// The greatest common divisor of the absolute values of `a` and `b`, which is `0` only when both
+/// are `0`.
+pub fn gcd(a: i64, b: i64) -> i64 {
+    let (mut a, mut b) = (a.abs(), b.abs());
+
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+/// The least common multiple of the absolute values of `a` and `b`.
+pub fn lcm(a: i64, b: i64) -> i64 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+
+    (a / gcd(a, b) * b).abs()
+}
+
+/// The greatest common divisor `g` of `a` and `b` together with coefficients `x` and `y` such that
+/// `a * x + b * y == g`.
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+
+    match old_r < 0 {
+        true => (-old_r, -old_x, -old_y),
+        false => (old_r, old_x, old_y),
+    }
+}
+
+/// `a * b` modulo `modulus`, in the range `0..modulus`. Multiplies in 128 bits so it does not
+/// overflow for any 64-bit modulus.
+pub fn mul_mod(a: i64, b: i64, modulus: i64) -> i64 {
+    (a as i128 * b as i128).rem_euclid(modulus as i128) as i64
+}
+
+/// `base` raised to `exponent` modulo `modulus` by repeated squaring.
+pub fn pow_mod(base: i64, mut exponent: u64, modulus: i64) -> i64 {
+    let mut base = base.rem_euclid(modulus);
+    let mut result = 1 % modulus;
+
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mul_mod(result, base, modulus);
+        }
+
+        base = mul_mod(base, base, modulus);
+        exponent >>= 1;
+    }
+
+    result
+}
+
+/// The `x` in `0..modulus` with `a * x == 1` modulo `modulus`, which only exists when `a` and
+/// `modulus` are coprime.
+pub fn mod_inverse(a: i64, modulus: i64) -> Option<i64> {
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+
+    (g == 1).then(|| x.rem_euclid(modulus))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(-4, 6), 2);
+        assert_eq!(gcd(0, -5), 5);
+        assert_eq!(gcd(0, 0), 0);
+    }
+
+    #[test]
+    fn test_lcm() {
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(-3, 5), 15);
+        assert_eq!(lcm(0, 5), 0);
+        assert_eq!([18, 28, 44].into_iter().fold(1, lcm), 2772);
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        for (a, b) in [(240, 46), (-7, 3), (0, 9), (17, 0)] {
+            let (g, x, y) = extended_gcd(a, b);
+
+            assert_eq!(g, gcd(a, b));
+            assert_eq!(a * x + b * y, g);
+        }
+    }
+
+    #[test]
+    fn test_modular_arithmetic() {
+        assert_eq!(mul_mod(-3, 4, 5), 3);
+        assert_eq!(mul_mod(i64::MAX, i64::MAX, 1_000_000_007), 737564071);
+        assert_eq!(pow_mod(3, 200, 13), 9);
+        assert_eq!(pow_mod(7, 0, 1), 0);
+        assert_eq!(mod_inverse(3, 10), Some(7));
+        assert_eq!(mod_inverse(-3, 10), Some(3));
+        assert_eq!(mod_inverse(4, 10), None);
+    }
+}
@@ -0,0 +1,183 @@
+use crate::helpers::math::lcm;
+use std::cmp::Ordering;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Moon<const D: usize> {
+    pub position: [i64; D],
+    pub velocity: [i64; D],
+}
+
+impl<const D: usize> Moon<D> {
+    pub fn energy(&self) -> i64 {
+        let potential = self.position.iter().map(|value| value.abs()).sum::<i64>();
+        let kinetic = self.velocity.iter().map(|value| value.abs()).sum::<i64>();
+
+        potential * kinetic
+    }
+}
+
+/// Moons pulling on each other in `D` dimensions. Every axis is simulated independently of the
+/// others, which allows finding the period of the whole system from the period of every axis.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MoonSystem<const D: usize> {
+    moons: Vec<Moon<D>>,
+}
+
+/// Applies one time step to a single axis: gravity changes the velocities, then the velocities
+/// change the positions.
+fn step_axis(positions: &mut [i64], velocities: &mut [i64]) {
+    for (i, position) in positions.iter().enumerate() {
+        velocities[i] += positions
+            .iter()
+            .map(|other| match position.cmp(other) {
+                Ordering::Less => 1,
+                Ordering::Equal => 0,
+                Ordering::Greater => -1,
+            })
+            .sum::<i64>();
+    }
+
+    for (position, velocity) in positions.iter_mut().zip(velocities.iter()) {
+        *position += velocity;
+    }
+}
+
+impl<const D: usize> MoonSystem<D> {
+    pub fn new(positions: impl IntoIterator<Item = [i64; D]>) -> Self {
+        Self {
+            moons: positions
+                .into_iter()
+                .map(|position| Moon {
+                    position,
+                    velocity: [0; D],
+                })
+                .collect(),
+        }
+    }
+
+    /// Parses one moon per line from lines such as `<x=-1, y=0, z=2>`, only looking at the
+    /// numbers.
+    pub fn from_input(input: &str) -> Self {
+        Self::new(
+            input
+                .lines()
+                .filter(|line| !line.trim().is_empty())
+                .map(|line| {
+                    let values = line
+                        .split(|value: char| !(value.is_ascii_digit() || value == '-'))
+                        .filter(|value| !value.is_empty())
+                        .map(|value| value.parse::<i64>().unwrap())
+                        .collect::<Vec<_>>();
+
+                    values
+                        .try_into()
+                        .unwrap_or_else(|_| panic!("Expected {D} coordinates in '{line}'"))
+                }),
+        )
+    }
+
+    pub fn moons(&self) -> &[Moon<D>] {
+        &self.moons
+    }
+
+    fn axis(&self, axis: usize) -> (Vec<i64>, Vec<i64>) {
+        self.moons
+            .iter()
+            .map(|moon| (moon.position[axis], moon.velocity[axis]))
+            .unzip()
+    }
+
+    pub fn step(&mut self) {
+        for axis in 0..D {
+            let (mut positions, mut velocities) = self.axis(axis);
+            step_axis(&mut positions, &mut velocities);
+
+            for (moon, (position, velocity)) in self
+                .moons
+                .iter_mut()
+                .zip(positions.into_iter().zip(velocities))
+            {
+                moon.position[axis] = position;
+                moon.velocity[axis] = velocity;
+            }
+        }
+    }
+
+    pub fn simulate(&mut self, steps: usize) {
+        for _ in 0..steps {
+            self.step();
+        }
+    }
+
+    pub fn total_energy(&self) -> i64 {
+        self.moons.iter().map(Moon::energy).sum()
+    }
+
+    /// The number of steps after which the positions and velocities along `axis` first repeat.
+    /// A step can be undone, so the first repeated state is always the current one.
+    pub fn axis_period(&self, axis: usize) -> i64 {
+        let initial = self.axis(axis);
+        let (mut positions, mut velocities) = initial.clone();
+        let mut steps = 0;
+
+        loop {
+            step_axis(&mut positions, &mut velocities);
+            steps += 1;
+
+            if positions == initial.0 && velocities == initial.1 {
+                return steps;
+            }
+        }
+    }
+
+    /// The number of steps after which the whole system first repeats a previous state.
+    pub fn period(&self) -> i64 {
+        (0..D).map(|axis| self.axis_period(axis)).fold(1, lcm)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MOONS: &str = "<x=-1, y=0, z=2>
+<x=2, y=-10, z=-7>
+<x=4, y=-8, z=8>
+<x=3, y=5, z=-1>";
+
+    #[test]
+    fn test_simulate() {
+        let mut system = MoonSystem::<3>::from_input(MOONS);
+        system.simulate(10);
+
+        assert_eq!(
+            system.moons()[0],
+            Moon {
+                position: [2, 1, -3],
+                velocity: [-3, -2, 1]
+            }
+        );
+        assert_eq!(system.total_energy(), 179);
+    }
+
+    #[test]
+    fn test_period() {
+        let system = MoonSystem::<3>::from_input(MOONS);
+        assert_eq!(system.period(), 2772);
+
+        let system = MoonSystem::<3>::from_input(
+            "<x=-8, y=-10, z=0>\n<x=5, y=5, z=10>\n<x=2, y=-7, z=3>\n<x=9, y=-8, z=-3>",
+        );
+        assert_eq!(system.period(), 4686774924);
+    }
+
+    #[test]
+    fn test_other_dimensions() {
+        let system = MoonSystem::new([[0], [2]]);
+        assert_eq!(system.period(), 6);
+
+        let mut system = MoonSystem::new([[0, 0], [1, 3]]);
+        system.step();
+        assert_eq!(system.moons()[1].position, [0, 2]);
+    }
+}
@@ -0,0 +1,357 @@
+use crate::helpers::{Input, Intcode, IntcodeComputer, Interrupt};
+use std::collections::VecDeque;
+
+/// The address of the NAT, packets sent here never reach a computer.
+pub const NAT_ADDRESS: isize = 255;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Packet {
+    pub source: isize,
+    pub destination: isize,
+    pub x: isize,
+    pub y: isize,
+}
+
+/// The packets waiting to be read by a single computer.
+pub trait PacketQueue: Default {
+    fn push(&mut self, packet: Packet);
+    fn pop(&mut self) -> Option<Packet>;
+}
+
+impl PacketQueue for VecDeque<Packet> {
+    fn push(&mut self, packet: Packet) {
+        self.push_back(packet);
+    }
+
+    fn pop(&mut self) -> Option<Packet> {
+        self.pop_front()
+    }
+}
+
+/// What happened on the network during one round, in which every computer ran until it had read
+/// all its packets, or `-1` if there were none, and waited for more input.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Activity {
+    pub sent: usize,
+    pub received: usize,
+}
+
+/// Decides when the network is idle, which is when the NAT wakes it up.
+pub trait IdlePolicy {
+    fn is_idle(&mut self, activity: &Activity) -> bool;
+}
+
+/// Considers the network idle after `required` rounds in a row in which no computer sent or
+/// received a packet.
+pub struct QuietRounds {
+    pub required: usize,
+    quiet: usize,
+}
+
+impl QuietRounds {
+    pub fn new(required: usize) -> Self {
+        Self { required, quiet: 0 }
+    }
+}
+
+impl Default for QuietRounds {
+    fn default() -> Self {
+        Self::new(1)
+    }
+}
+
+impl IdlePolicy for QuietRounds {
+    fn is_idle(&mut self, activity: &Activity) -> bool {
+        match activity.sent == 0 && activity.received == 0 {
+            true => self.quiet += 1,
+            false => self.quiet = 0,
+        }
+
+        self.quiet >= self.required
+    }
+}
+
+/// Receives the packets sent to the NAT address and picks the packet to wake the network with.
+pub trait Nat {
+    fn receive(&mut self, packet: Packet);
+    fn wake(&mut self) -> Option<Packet>;
+}
+
+/// Sends the last packet it received to the computer at address `0`.
+#[derive(Default)]
+pub struct LastPacketNat {
+    last: Option<Packet>,
+}
+
+impl Nat for LastPacketNat {
+    fn receive(&mut self, packet: Packet) {
+        self.last = Some(packet);
+    }
+
+    fn wake(&mut self) -> Option<Packet> {
+        self.last.map(|packet| Packet {
+            source: NAT_ADDRESS,
+            destination: 0,
+            ..packet
+        })
+    }
+}
+
+/// Every packet sent on the network in order, including those sent by the NAT.
+#[derive(Clone, Debug, Default)]
+pub struct PacketLog {
+    packets: Vec<Packet>,
+    /// Kept up to date on every packet, so checking for a repeat does not scan the whole log.
+    last_nat_y: Option<isize>,
+    repeated_nat_y: Option<isize>,
+}
+
+impl PacketLog {
+    fn push(&mut self, packet: Packet) {
+        if packet.source == NAT_ADDRESS {
+            if self.repeated_nat_y.is_none() && self.last_nat_y == Some(packet.y) {
+                self.repeated_nat_y = Some(packet.y);
+            }
+            self.last_nat_y = Some(packet.y);
+        }
+
+        self.packets.push(packet);
+    }
+
+    pub fn packets(&self) -> &[Packet] {
+        &self.packets
+    }
+
+    pub fn first_packet_to(&self, destination: isize) -> Option<&Packet> {
+        self.packets
+            .iter()
+            .find(|packet| packet.destination == destination)
+    }
+
+    /// The first `y` value that the NAT sends twice in a row.
+    pub fn first_repeated_nat_y(&self) -> Option<isize> {
+        self.repeated_nat_y
+    }
+}
+
+struct Node<Q> {
+    computer: IntcodeComputer,
+    input: Input,
+    queue: Q,
+    output: Vec<isize>,
+}
+
+pub struct Network<Q = VecDeque<Packet>, I = QuietRounds, N = LastPacketNat> {
+    nodes: Vec<Node<Q>>,
+    idle: I,
+    nat: N,
+    log: PacketLog,
+}
+
+impl Network {
+    pub fn new(program: &Intcode, size: usize) -> Self {
+        Self::with_policies(
+            program,
+            size,
+            QuietRounds::default(),
+            LastPacketNat::default(),
+        )
+    }
+}
+
+impl<Q: PacketQueue, I: IdlePolicy, N: Nat> Network<Q, I, N> {
+    /// Boots `size` computers running `program`, each one first reading its own address.
+    pub fn with_policies(program: &Intcode, size: usize, idle: I, nat: N) -> Self {
+        let nodes = (0..size)
+            .map(|address| Node {
+                computer: IntcodeComputer::new(program.clone()),
+                input: Input::new(vec![address as isize]),
+                queue: Q::default(),
+                output: Vec::new(),
+            })
+            .collect();
+
+        Self {
+            nodes,
+            idle,
+            nat,
+            log: PacketLog::default(),
+        }
+    }
+
+    pub fn log(&self) -> &PacketLog {
+        &self.log
+    }
+
+    fn deliver(&mut self, packet: Packet) {
+        self.log.push(packet);
+
+        if packet.destination == NAT_ADDRESS {
+            self.nat.receive(packet);
+        } else if let Some(node) = usize::try_from(packet.destination)
+            .ok()
+            .and_then(|destination| self.nodes.get_mut(destination))
+        {
+            node.queue.push(packet);
+        }
+    }
+
+    fn round(&mut self) -> Activity {
+        let mut activity = Activity::default();
+        let mut sent = Vec::new();
+
+        for (address, node) in self.nodes.iter_mut().enumerate() {
+            let mut has_read = false;
+
+            loop {
+                match node.computer.run(&mut node.input) {
+                    Interrupt::Output(value) => {
+                        node.output.push(value);
+
+                        if let [destination, x, y] = node.output[..] {
+                            sent.push(Packet {
+                                source: address as isize,
+                                destination,
+                                x,
+                                y,
+                            });
+                            node.output.clear();
+                        }
+                    }
+                    Interrupt::AwaitingInput if !has_read => {
+                        has_read = true;
+
+                        let mut empty = true;
+                        while let Some(packet) = node.queue.pop() {
+                            node.input.push(packet.x);
+                            node.input.push(packet.y);
+                            activity.received += 1;
+                            empty = false;
+                        }
+
+                        if empty {
+                            node.input.push(-1);
+                        }
+                    }
+                    Interrupt::AwaitingInput | Interrupt::Halted => break,
+                }
+            }
+        }
+
+        activity.sent = sent.len();
+        for packet in sent {
+            self.deliver(packet);
+        }
+
+        activity
+    }
+
+    /// Runs the network until `done` holds for the packet log. Returns `None` when the network
+    /// becomes idle and the NAT has nothing to wake it with, or when every computer halted.
+    pub fn run_until(&mut self, done: impl Fn(&PacketLog) -> bool) -> Option<&PacketLog> {
+        while !done(&self.log) {
+            if self.nodes.iter().all(|node| node.computer.is_halted()) {
+                return None;
+            }
+
+            let activity = self.round();
+
+            if self.idle.is_idle(&activity) {
+                let packet = self.nat.wake()?;
+                self.deliver(packet);
+            }
+        }
+
+        Some(&self.log)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Sends `(255, address, 42)` once and then reads input forever.
+    const PROGRAM: &str = "3,100,104,255,4,100,104,42,3,101,1105,1,8";
+
+    #[test]
+    fn test_first_packet_to_nat() {
+        let mut network = Network::new(&Intcode::from_input(PROGRAM), 3);
+        let log = network
+            .run_until(|log| log.first_packet_to(NAT_ADDRESS).is_some())
+            .unwrap();
+
+        assert_eq!(
+            log.first_packet_to(NAT_ADDRESS),
+            Some(&Packet {
+                source: 0,
+                destination: NAT_ADDRESS,
+                x: 0,
+                y: 42
+            })
+        );
+    }
+
+    #[test]
+    fn test_nat_wakes_network() {
+        let mut network = Network::new(&Intcode::from_input(PROGRAM), 3);
+        let log = network
+            .run_until(|log| log.first_repeated_nat_y().is_some())
+            .unwrap();
+
+        assert_eq!(log.first_repeated_nat_y(), Some(42));
+        assert_eq!(
+            log.packets().last(),
+            Some(&Packet {
+                source: NAT_ADDRESS,
+                destination: 0,
+                x: 2,
+                y: 42
+            })
+        );
+    }
+
+    #[test]
+    fn test_repeated_nat_y() {
+        let mut log = PacketLog::default();
+        let nat = |y| Packet {
+            source: NAT_ADDRESS,
+            destination: 0,
+            x: 0,
+            y,
+        };
+
+        log.push(nat(3));
+        log.push(Packet {
+            source: 0,
+            ..nat(3)
+        });
+        log.push(nat(2));
+        assert_eq!(log.first_repeated_nat_y(), None);
+
+        log.push(nat(2));
+        log.push(nat(1));
+        log.push(nat(1));
+        assert_eq!(log.first_repeated_nat_y(), Some(2));
+    }
+
+    #[test]
+    fn test_idle_network_without_nat_packet() {
+        let mut network = Network::new(&Intcode::from_input("3,100,3,101,1105,1,2"), 2);
+
+        assert!(network.run_until(|_| false).is_none());
+    }
+
+    #[test]
+    fn test_quiet_rounds() {
+        let mut idle = QuietRounds::new(2);
+        let busy = Activity {
+            sent: 1,
+            received: 0,
+        };
+
+        assert!(!idle.is_idle(&Activity::default()));
+        assert!(!idle.is_idle(&busy));
+        assert!(!idle.is_idle(&Activity::default()));
+        assert!(idle.is_idle(&Activity::default()));
+    }
+}
@@ -0,0 +1,339 @@
+use crate::helpers::{Grid, Point};
+use std::fmt::Display;
+
+/// The 4x6 letters, as rows of `#` and `.` joined together. Most letters are 4 pixels wide, 'Y'
+/// is 5.
+const SMALL_FONT: [(char, &str); 18] = [
+    ('A', ".##.#..##..######..##..#"),
+    ('B', "###.#..####.#..##..####."),
+    ('C', ".##.#..##...#...#..#.##."),
+    ('E', "#####...###.#...#...####"),
+    ('F', "#####...###.#...#...#..."),
+    ('G', ".##.#..##...#.###..#.###"),
+    ('H', "#..##..######..##..##..#"),
+    ('I', ".###..#...#...#...#..###"),
+    ('J', "..##...#...#...##..#.##."),
+    ('K', "#..##.#.##..#.#.#.#.#..#"),
+    ('L', "#...#...#...#...#...####"),
+    ('O', ".##.#..##..##..##..#.##."),
+    ('P', "###.#..##..####.#...#..."),
+    ('R', "###.#..##..####.#.#.#..#"),
+    ('S', ".####...#....##....####."),
+    ('U', "#..##..##..##..##..#.##."),
+    ('Y', "#...##...#.#.#...#....#....#.."),
+    ('Z', "####...#..#..#..#...####"),
+];
+
+/// The 6x10 letters, as rows of `#` and `.` joined together.
+const LARGE_FONT: [(char, &str); 15] = [
+    (
+        'A',
+        "..##...#..#.#....##....##....########....##....##....##....#",
+    ),
+    (
+        'B',
+        "#####.#....##....##....######.#....##....##....##....######.",
+    ),
+    (
+        'C',
+        ".####.#....##.....#.....#.....#.....#.....#.....#....#.####.",
+    ),
+    (
+        'E',
+        "#######.....#.....#.....#####.#.....#.....#.....#.....######",
+    ),
+    (
+        'F',
+        "#######.....#.....#.....#####.#.....#.....#.....#.....#.....",
+    ),
+    (
+        'G',
+        ".####.#....##.....#.....#.....#..####....##....##...##.###.#",
+    ),
+    (
+        'H',
+        "#....##....##....##....########....##....##....##....##....#",
+    ),
+    (
+        'J',
+        "...###....#.....#.....#.....#.....#.....#.#...#.#...#..###..",
+    ),
+    (
+        'K',
+        "#....##...#.#..#..#.#...##....##....#.#...#..#..#...#.#....#",
+    ),
+    (
+        'L',
+        "#.....#.....#.....#.....#.....#.....#.....#.....#.....######",
+    ),
+    (
+        'N',
+        "#....###...###...##.#..##.#..##..#.##..#.##...###...###....#",
+    ),
+    (
+        'P',
+        "#####.#....##....##....######.#.....#.....#.....#.....#.....",
+    ),
+    (
+        'R',
+        "#####.#....##....##....######.#..#..#...#.#...#.#....##....#",
+    ),
+    (
+        'X',
+        "#....##....#.#..#..#..#...##....##...#..#..#..#.#....##....#",
+    ),
+    (
+        'Z',
+        "######.....#.....#....#....#....#....#....#.....#.....######",
+    ),
+];
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Font {
+    Small,
+    Large,
+}
+
+impl Font {
+    fn from_height(height: usize) -> Option<Self> {
+        match height {
+            6 => Some(Font::Small),
+            10 => Some(Font::Large),
+            _ => None,
+        }
+    }
+
+    fn height(&self) -> usize {
+        match self {
+            Font::Small => 6,
+            Font::Large => 10,
+        }
+    }
+
+    fn glyphs(&self) -> &'static [(char, &'static str)] {
+        match self {
+            Font::Small => &SMALL_FONT,
+            Font::Large => &LARGE_FONT,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum OcrError {
+    Empty,
+    UnsupportedHeight(usize),
+    /// The bitmaps of the glyphs that did not match any letter, as lines of `#` and `.`.
+    UnrecognizedGlyphs(Vec<String>),
+}
+
+impl Display for OcrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OcrError::Empty => write!(f, "there are no letters to recognize."),
+            OcrError::UnsupportedHeight(height) => {
+                write!(f, "no font is {height} pixels high.")
+            }
+            OcrError::UnrecognizedGlyphs(glyphs) => {
+                writeln!(f, "could not recognize {} glyph(s):", glyphs.len())?;
+                write!(f, "{}", glyphs.join("\n\n"))
+            }
+        }
+    }
+}
+
+/// A glyph as a list of columns, each column being a list of pixels from top to bottom.
+type Columns = Vec<Vec<bool>>;
+
+fn trim_columns(columns: &[Vec<bool>]) -> &[Vec<bool>] {
+    let is_lit = |column: &Vec<bool>| column.iter().any(|pixel| *pixel);
+
+    match columns.iter().position(is_lit) {
+        None => &[],
+        Some(start) => &columns[start..=columns.iter().rposition(is_lit).unwrap()],
+    }
+}
+
+fn columns_of(bitmap: &str, height: usize) -> Columns {
+    let width = bitmap.len() / height;
+    let pixels = bitmap.chars().map(|pixel| pixel == '#').collect::<Vec<_>>();
+
+    (0..width)
+        .map(|x| pixels.iter().skip(x).step_by(width).copied().collect())
+        .collect()
+}
+
+fn render_columns(columns: &[Vec<bool>]) -> String {
+    (0..columns.first().map_or(0, |column| column.len()))
+        .map(|y| {
+            columns
+                .iter()
+                .map(|column| if column[y] { '#' } else { '.' })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Reads the letters drawn by the lit pixels of the grid. The font is picked by the height of the
+/// lit area and letters are separated by columns without lit pixels, so any margin around the text
+/// is ignored.
+pub fn recognize(grid: &Grid<bool>) -> Result<String, OcrError> {
+    let lit = grid
+        .iter()
+        .filter(|(_, pixel)| **pixel)
+        .map(|(point, _)| point)
+        .collect::<Vec<_>>();
+
+    if lit.is_empty() {
+        return Err(OcrError::Empty);
+    }
+
+    let min = Point::new(
+        lit.iter().map(|point| point.x).min().unwrap(),
+        lit.iter().map(|point| point.y).min().unwrap(),
+    );
+    let max = Point::new(
+        lit.iter().map(|point| point.x).max().unwrap(),
+        lit.iter().map(|point| point.y).max().unwrap(),
+    );
+
+    let height = (max.y - min.y + 1) as usize;
+    let font = Font::from_height(height).ok_or(OcrError::UnsupportedHeight(height))?;
+
+    let columns = (min.x..=max.x)
+        .map(|x| {
+            (min.y..=max.y)
+                .map(|y| grid.get(&Point::new(x, y)).copied().unwrap_or(false))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Columns>();
+
+    let glyphs = font
+        .glyphs()
+        .iter()
+        .map(|(letter, bitmap)| (*letter, columns_of(bitmap, font.height())))
+        .collect::<Vec<_>>();
+
+    let mut text = String::new();
+    let mut unrecognized = Vec::new();
+
+    for glyph in columns.split(|column| column.iter().all(|pixel| !pixel)) {
+        if glyph.is_empty() {
+            continue;
+        }
+
+        match glyphs
+            .iter()
+            .find(|(_, bitmap)| trim_columns(bitmap) == glyph)
+        {
+            Some((letter, _)) => text.push(*letter),
+            None => unrecognized.push(render_columns(glyph)),
+        }
+    }
+
+    match unrecognized.is_empty() {
+        true => Ok(text),
+        false => Err(OcrError::UnrecognizedGlyphs(unrecognized)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: &str) -> Grid<bool> {
+        Grid::parse(input, |pixel| pixel == '#')
+    }
+
+    #[test]
+    fn test_small_font() {
+        let grid = parse(
+            "......................
+..#..#.####.#.....###.
+..#..#.#....#......#..
+..####.###..#......#..
+..#..#.#....#......#..
+..#..#.#....#......#..
+..#..#.####.####..###.
+......................",
+        );
+
+        assert_eq!(recognize(&grid), Ok("HELI".to_string()));
+    }
+
+    #[test]
+    fn test_large_font() {
+        let grid = parse(
+            "#....#..######
+#....#..#.....
+#....#..#.....
+#....#..#.....
+######..#####.
+#....#..#.....
+#....#..#.....
+#....#..#.....
+#....#..#.....
+#....#..######",
+        );
+
+        assert_eq!(recognize(&grid), Ok("HE".to_string()));
+    }
+
+    /// Draws the letters of the font next to each other, separated by an empty column.
+    fn draw(font: Font) -> (String, Grid<bool>) {
+        let rows = (0..font.height())
+            .map(|y| {
+                font.glyphs()
+                    .iter()
+                    .map(|(_, bitmap)| {
+                        let width = bitmap.len() / font.height();
+                        &bitmap[y * width..(y + 1) * width]
+                    })
+                    .collect::<Vec<_>>()
+                    .join(".")
+            })
+            .collect::<Vec<_>>();
+        let letters = font.glyphs().iter().map(|(letter, _)| letter).collect();
+
+        (letters, parse(&rows.join("\n")))
+    }
+
+    #[test]
+    fn test_every_glyph() {
+        for font in [Font::Small, Font::Large] {
+            let (letters, grid) = draw(font);
+            assert_eq!(recognize(&grid), Ok(letters), "{font:?}");
+        }
+    }
+
+    #[test]
+    fn test_small_font_y() {
+        let grid = parse(
+            "#...#.#..#
+#...#.#..#
+.#.#..####
+..#...#..#
+..#...#..#
+..#...#..#",
+        );
+
+        assert_eq!(recognize(&grid), Ok("YH".to_string()));
+    }
+
+    #[test]
+    fn test_unrecognized_glyphs() {
+        let grid = parse("#..#.###\n#..#.#.#\n####.###\n#..#.#..\n#..#.#..\n#..#.###");
+
+        assert_eq!(
+            recognize(&grid),
+            Err(OcrError::UnrecognizedGlyphs(vec![
+                "###\n#.#\n###\n#..\n#..\n###".to_string()
+            ]))
+        );
+        assert_eq!(
+            recognize(&parse("#\n#")),
+            Err(OcrError::UnsupportedHeight(2))
+        );
+        assert_eq!(recognize(&parse("..")), Err(OcrError::Empty));
+    }
+}
@@ -0,0 +1,263 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+use std::iter;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum OrbitError {
+    InvalidLine(String),
+    MultipleParents(String),
+    NoRoot,
+    MultipleRoots(Vec<String>),
+    Cycle(Vec<String>),
+}
+
+impl Display for OrbitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OrbitError::InvalidLine(line) => write!(f, "'{line}' is not of the form 'A)B'."),
+            OrbitError::MultipleParents(object) => {
+                write!(f, "{object} orbits more than one object.")
+            }
+            OrbitError::NoRoot => write!(f, "every object orbits another object."),
+            OrbitError::MultipleRoots(roots) => {
+                write!(
+                    f,
+                    "found multiple objects orbiting nothing: {}.",
+                    roots.join(", ")
+                )
+            }
+            OrbitError::Cycle(objects) => {
+                write!(f, "objects are part of a cycle: {}.", objects.join(", "))
+            }
+        }
+    }
+}
+
+/// A tree of objects where every object except the root directly orbits exactly one other object.
+#[derive(Debug)]
+pub struct OrbitMap {
+    root: String,
+    parents: HashMap<String, String>,
+    children: HashMap<String, Vec<String>>,
+}
+
+impl OrbitMap {
+    pub fn from_input(input: &str) -> Result<Self, OrbitError> {
+        let mut parents = HashMap::new();
+        let mut children: HashMap<String, Vec<String>> = HashMap::new();
+
+        for line in input.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            let (center, object) = line
+                .split_once(')')
+                .filter(|(center, object)| !center.is_empty() && !object.is_empty())
+                .ok_or_else(|| OrbitError::InvalidLine(line.to_string()))?;
+
+            if parents
+                .insert(object.to_string(), center.to_string())
+                .is_some()
+            {
+                return Err(OrbitError::MultipleParents(object.to_string()));
+            }
+
+            children
+                .entry(center.to_string())
+                .or_default()
+                .push(object.to_string());
+        }
+
+        let mut roots = children
+            .keys()
+            .filter(|object| !parents.contains_key(*object))
+            .cloned()
+            .collect::<Vec<_>>();
+        roots.sort();
+
+        let root = match roots.len() {
+            0 if parents.is_empty() => return Err(OrbitError::NoRoot),
+            0 => {
+                let mut cycle = parents.keys().cloned().collect::<Vec<_>>();
+                cycle.sort();
+                return Err(OrbitError::Cycle(cycle));
+            }
+            1 => roots.remove(0),
+            _ => return Err(OrbitError::MultipleRoots(roots)),
+        };
+
+        let map = Self {
+            root,
+            parents,
+            children,
+        };
+
+        // Every object reaches the root unless it is part of a cycle disconnected from it.
+        let reachable = map.descendants(&map.root);
+        let mut cycle = map
+            .parents
+            .keys()
+            .filter(|object| !reachable.contains(object.as_str()))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        if !cycle.is_empty() {
+            cycle.sort();
+            return Err(OrbitError::Cycle(cycle));
+        }
+
+        Ok(map)
+    }
+
+    pub fn root(&self) -> &str {
+        &self.root
+    }
+
+    pub fn contains(&self, object: &str) -> bool {
+        object == self.root || self.parents.contains_key(object)
+    }
+
+    fn descendants<'a>(&'a self, object: &'a str) -> HashSet<&'a str> {
+        let mut visited = HashSet::from([object]);
+        let mut stack = vec![object];
+
+        while let Some(object) = stack.pop() {
+            for child in self.children.get(object).into_iter().flatten() {
+                if visited.insert(child) {
+                    stack.push(child);
+                }
+            }
+        }
+
+        visited
+    }
+
+    /// The objects `object` orbits directly or indirectly, starting with the one it orbits
+    /// directly and ending with the root.
+    pub fn ancestors<'a>(&'a self, object: &'a str) -> Vec<&'a str> {
+        let mut ancestors = Vec::new();
+        let mut current = object;
+
+        while let Some(parent) = self.parents.get(current) {
+            ancestors.push(parent.as_str());
+            current = parent;
+        }
+
+        ancestors
+    }
+
+    /// The number of objects `object` orbits directly and indirectly.
+    pub fn depth(&self, object: &str) -> usize {
+        self.ancestors(object).len()
+    }
+
+    /// The total number of direct and indirect orbits in the map.
+    pub fn total_orbits(&self) -> usize {
+        let mut total = 0;
+        let mut stack = vec![(self.root.as_str(), 0)];
+
+        while let Some((object, depth)) = stack.pop() {
+            total += depth;
+
+            for child in self.children.get(object).into_iter().flatten() {
+                stack.push((child, depth + 1));
+            }
+        }
+
+        total
+    }
+
+    /// The deepest object that both `a` and `b` are or orbit.
+    pub fn lowest_common_ancestor<'a>(&'a self, a: &'a str, b: &'a str) -> Option<&'a str> {
+        if !self.contains(a) || !self.contains(b) {
+            return None;
+        }
+
+        let path_b = iter::once(b)
+            .chain(self.ancestors(b))
+            .collect::<HashSet<_>>();
+
+        iter::once(a)
+            .chain(self.ancestors(a))
+            .find(|object| path_b.contains(object))
+    }
+
+    /// The number of orbital transfers needed to move from the object `from` orbits to the object
+    /// `to` orbits.
+    pub fn transfers(&self, from: &str, to: &str) -> Option<usize> {
+        let from = self.parents.get(from)?;
+        let to = self.parents.get(to)?;
+        let common = self.lowest_common_ancestor(from, to)?;
+
+        Some(self.depth(from) + self.depth(to) - 2 * self.depth(common))
+    }
+
+    /// Renders the map in the DOT language with an edge from every object to the objects
+    /// orbiting it.
+    pub fn to_dot(&self) -> String {
+        let mut edges = self
+            .parents
+            .iter()
+            .map(|(object, center)| format!("    \"{center}\" -> \"{object}\";"))
+            .collect::<Vec<_>>();
+        edges.sort();
+
+        format!("digraph orbits {{\n{}\n}}\n", edges.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: &str = "COM)B\nB)C\nC)D\nD)E\nE)F\nB)G\nG)H\nD)I\nE)J\nJ)K\nK)L";
+
+    #[test]
+    fn test_total_orbits() {
+        let map = OrbitMap::from_input(MAP).unwrap();
+
+        assert_eq!(map.root(), "COM");
+        assert_eq!(map.depth("D"), 3);
+        assert_eq!(map.ancestors("D"), vec!["C", "B", "COM"]);
+        assert_eq!(map.total_orbits(), 42);
+    }
+
+    #[test]
+    fn test_lowest_common_ancestor() {
+        let map = OrbitMap::from_input(MAP).unwrap();
+
+        assert_eq!(map.lowest_common_ancestor("L", "I"), Some("D"));
+        assert_eq!(map.lowest_common_ancestor("H", "F"), Some("B"));
+        assert_eq!(map.lowest_common_ancestor("E", "K"), Some("E"));
+        assert_eq!(map.lowest_common_ancestor("E", "X"), None);
+        assert_eq!(map.transfers("K", "I"), Some(2));
+    }
+
+    #[test]
+    fn test_validation() {
+        assert_eq!(
+            OrbitMap::from_input("COM)B\nB-C").unwrap_err(),
+            OrbitError::InvalidLine("B-C".to_string())
+        );
+        assert_eq!(
+            OrbitMap::from_input("COM)B\nX)B").unwrap_err(),
+            OrbitError::MultipleParents("B".to_string())
+        );
+        assert_eq!(
+            OrbitMap::from_input("COM)B\nX)Y").unwrap_err(),
+            OrbitError::MultipleRoots(vec!["COM".to_string(), "X".to_string()])
+        );
+        assert_eq!(
+            OrbitMap::from_input("COM)B\nX)Y\nY)X").unwrap_err(),
+            OrbitError::Cycle(vec!["X".to_string(), "Y".to_string()])
+        );
+        assert_eq!(OrbitMap::from_input("").unwrap_err(), OrbitError::NoRoot);
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let map = OrbitMap::from_input("COM)B\nB)C\nB)D").unwrap();
+
+        assert_eq!(
+            map.to_dot(),
+            "digraph orbits {\n    \"B\" -> \"C\";\n    \"B\" -> \"D\";\n    \"COM\" -> \"B\";\n}\n"
+        );
+    }
+}
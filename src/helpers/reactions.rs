@@ -0,0 +1,290 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+
+pub const ORE: &str = "ORE";
+pub const FUEL: &str = "FUEL";
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ReactionError {
+    InvalidLine(String),
+    /// A reaction that produces nothing, so it can never make enough of its chemical.
+    ZeroOutput(String),
+    DuplicateProducer(String),
+    MissingRecipe(String),
+    Cycle(Vec<String>),
+}
+
+impl Display for ReactionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReactionError::InvalidLine(line) => write!(f, "'{line}' is not a valid reaction."),
+            ReactionError::ZeroOutput(chemical) => {
+                write!(f, "the reaction for {chemical} produces nothing.")
+            }
+            ReactionError::DuplicateProducer(chemical) => {
+                write!(f, "{chemical} is produced by more than one reaction.")
+            }
+            ReactionError::MissingRecipe(chemical) => {
+                write!(f, "no reaction produces {chemical}.")
+            }
+            ReactionError::Cycle(chemicals) => {
+                write!(
+                    f,
+                    "reactions form a cycle between: {}.",
+                    chemicals.join(", ")
+                )
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Reaction {
+    quantity: u64,
+    inputs: Vec<(String, u64)>,
+}
+
+/// The outcome of producing some amount of a chemical from ore.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Production {
+    pub ore: u64,
+    /// The chemicals that were produced but not consumed, because reactions only run in whole
+    /// batches.
+    pub leftovers: HashMap<String, u64>,
+}
+
+pub struct Nanofactory {
+    reactions: HashMap<String, Reaction>,
+    /// Every chemical except ore, ordered such that a chemical only appears after all the
+    /// chemicals whose reactions consume it.
+    order: Vec<String>,
+}
+
+fn parse_quantity(value: &str) -> Option<(String, u64)> {
+    let (quantity, chemical) = value.trim().split_once(' ')?;
+
+    Some((chemical.trim().to_string(), quantity.parse().ok()?))
+}
+
+impl Nanofactory {
+    pub fn from_input(input: &str) -> Result<Self, ReactionError> {
+        let mut reactions = HashMap::new();
+
+        for line in input.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            let invalid = || ReactionError::InvalidLine(line.to_string());
+
+            let (inputs, output) = line.split_once("=>").ok_or_else(invalid)?;
+            let (chemical, quantity) = parse_quantity(output).ok_or_else(invalid)?;
+            let inputs = inputs
+                .split(',')
+                .map(parse_quantity)
+                .collect::<Option<Vec<_>>>()
+                .ok_or_else(invalid)?;
+
+            if quantity == 0 {
+                return Err(ReactionError::ZeroOutput(chemical));
+            }
+
+            if reactions
+                .insert(chemical.clone(), Reaction { quantity, inputs })
+                .is_some()
+            {
+                return Err(ReactionError::DuplicateProducer(chemical));
+            }
+        }
+
+        if !reactions.contains_key(FUEL) {
+            return Err(ReactionError::MissingRecipe(FUEL.to_string()));
+        }
+
+        if let Some(missing) = reactions
+            .values()
+            .flat_map(|reaction| reaction.inputs.iter())
+            .map(|(chemical, _)| chemical)
+            .find(|chemical| *chemical != ORE && !reactions.contains_key(*chemical))
+        {
+            return Err(ReactionError::MissingRecipe(missing.clone()));
+        }
+
+        let order = Self::topological_order(&reactions)?;
+
+        Ok(Self { reactions, order })
+    }
+
+    fn topological_order(
+        reactions: &HashMap<String, Reaction>,
+    ) -> Result<Vec<String>, ReactionError> {
+        let mut consumers = reactions
+            .keys()
+            .map(|chemical| (chemical.as_str(), 0))
+            .collect::<HashMap<&str, usize>>();
+
+        for (chemical, _) in reactions.values().flat_map(|reaction| &reaction.inputs) {
+            if let Some(count) = consumers.get_mut(chemical.as_str()) {
+                *count += 1;
+            }
+        }
+
+        let mut ready = consumers
+            .iter()
+            .filter(|(_, count)| **count == 0)
+            .map(|(chemical, _)| *chemical)
+            .collect::<Vec<_>>();
+        let mut order = Vec::new();
+
+        while let Some(chemical) = ready.pop() {
+            order.push(chemical.to_string());
+
+            for (input, _) in &reactions[chemical].inputs {
+                if let Some(count) = consumers.get_mut(input.as_str()) {
+                    *count -= 1;
+                    if *count == 0 {
+                        ready.push(input);
+                    }
+                }
+            }
+        }
+
+        if order.len() < reactions.len() {
+            let mut cycle = consumers
+                .into_iter()
+                .filter(|(_, count)| *count > 0)
+                .map(|(chemical, _)| chemical.to_string())
+                .collect::<Vec<_>>();
+            cycle.sort();
+
+            return Err(ReactionError::Cycle(cycle));
+        }
+
+        Ok(order)
+    }
+
+    /// Produces `amount` of `chemical`, running every reaction as few times as possible.
+    pub fn produce(&self, chemical: &str, amount: u64) -> Production {
+        assert!(
+            chemical == ORE || self.reactions.contains_key(chemical),
+            "No reaction produces {chemical}"
+        );
+
+        let mut needed = HashMap::from([(chemical.to_string(), amount)]);
+        let mut leftovers = HashMap::new();
+
+        for chemical in &self.order {
+            let Some(need) = needed.get(chemical).copied() else {
+                continue;
+            };
+
+            let reaction = &self.reactions[chemical];
+            let batches = need.div_ceil(reaction.quantity);
+
+            let surplus = batches * reaction.quantity - need;
+            if surplus > 0 {
+                leftovers.insert(chemical.clone(), surplus);
+            }
+
+            for (input, quantity) in &reaction.inputs {
+                *needed.entry(input.clone()).or_default() += batches * quantity;
+            }
+        }
+
+        Production {
+            ore: needed.get(ORE).copied().unwrap_or(0),
+            leftovers,
+        }
+    }
+
+    pub fn ore_for_fuel(&self, fuel: u64) -> u64 {
+        self.produce(FUEL, fuel).ore
+    }
+
+    /// The most fuel that can be produced with `ore`, using that the ore needed only grows with
+    /// the amount of fuel. `None` when fuel needs no ore at all, so there is no limit.
+    pub fn max_fuel(&self, ore: u64) -> Option<u64> {
+        if self.ore_for_fuel(1) == 0 {
+            return None;
+        }
+
+        let (mut low, mut high) = (0, 1);
+
+        while self.ore_for_fuel(high) <= ore {
+            low = high;
+            high *= 2;
+        }
+
+        while high - low > 1 {
+            let middle = low + (high - low) / 2;
+
+            match self.ore_for_fuel(middle) <= ore {
+                true => low = middle,
+                false => high = middle,
+            }
+        }
+
+        Some(low)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REACTIONS: &str = "10 ORE => 10 A
+1 ORE => 1 B
+7 A, 1 B => 1 C
+7 A, 1 C => 1 D
+7 A, 1 D => 1 E
+7 A, 1 E => 1 FUEL";
+
+    #[test]
+    fn test_produce() {
+        let factory = Nanofactory::from_input(REACTIONS).unwrap();
+        let production = factory.produce(FUEL, 1);
+
+        assert_eq!(production.ore, 31);
+        assert_eq!(production.leftovers, HashMap::from([("A".to_string(), 2)]));
+        assert_eq!(factory.produce("C", 2).ore, 22);
+    }
+
+    #[test]
+    fn test_max_fuel() {
+        let factory = Nanofactory::from_input(REACTIONS).unwrap();
+
+        assert_eq!(factory.max_fuel(30), Some(0));
+        assert_eq!(factory.max_fuel(31), Some(1));
+
+        let fuel = factory.max_fuel(1000).unwrap();
+        assert!(factory.ore_for_fuel(fuel) <= 1000);
+        assert!(factory.ore_for_fuel(fuel + 1) > 1000);
+
+        let free = Nanofactory::from_input("0 ORE => 1 FUEL").unwrap();
+        assert_eq!(free.max_fuel(1000), None);
+    }
+
+    #[test]
+    fn test_validation() {
+        assert_eq!(
+            Nanofactory::from_input("10 ORE -> 1 FUEL").err(),
+            Some(ReactionError::InvalidLine("10 ORE -> 1 FUEL".to_string()))
+        );
+        assert_eq!(
+            Nanofactory::from_input("1 ORE => 1 FUEL\n2 ORE => 1 FUEL").err(),
+            Some(ReactionError::DuplicateProducer("FUEL".to_string()))
+        );
+        assert_eq!(
+            Nanofactory::from_input("1 A => 1 FUEL").err(),
+            Some(ReactionError::MissingRecipe("A".to_string()))
+        );
+        assert_eq!(
+            Nanofactory::from_input("1 ORE => 1 A").err(),
+            Some(ReactionError::MissingRecipe("FUEL".to_string()))
+        );
+        assert_eq!(
+            Nanofactory::from_input("1 ORE => 0 A\n1 A => 1 FUEL").err(),
+            Some(ReactionError::ZeroOutput("A".to_string()))
+        );
+        assert_eq!(
+            Nanofactory::from_input("1 A => 1 FUEL\n1 B => 1 A\n1 A, 1 ORE => 1 B").err(),
+            Some(ReactionError::Cycle(vec!["A".to_string(), "B".to_string()]))
+        );
+    }
+}
@@ -0,0 +1,382 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+pub type Cost = usize;
+
+/// The outcome of exploring a graph: the distance to every visited node and the node it was
+/// reached from, which allows reconstructing the path back to the closest source.
+#[derive(Clone, Debug)]
+pub struct Search<N> {
+    distances: HashMap<N, Cost>,
+    parents: HashMap<N, N>,
+}
+
+impl<N: Clone + Eq + Hash> Search<N> {
+    fn new() -> Self {
+        Self {
+            distances: HashMap::new(),
+            parents: HashMap::new(),
+        }
+    }
+
+    pub fn distance(&self, node: &N) -> Option<Cost> {
+        self.distances.get(node).copied()
+    }
+
+    pub fn distances(&self) -> &HashMap<N, Cost> {
+        &self.distances
+    }
+
+    pub fn is_reachable(&self, node: &N) -> bool {
+        self.distances.contains_key(node)
+    }
+
+    pub fn reachable(&self) -> HashSet<N> {
+        self.distances.keys().cloned().collect()
+    }
+
+    /// The nodes on the path from the source to `target`, both included.
+    pub fn path(&self, target: &N) -> Option<Vec<N>> {
+        if !self.is_reachable(target) {
+            return None;
+        }
+
+        let mut path = vec![target.clone()];
+        while let Some(parent) = self.parents.get(path.last().unwrap()) {
+            path.push(parent.clone());
+        }
+
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Entry of the priority queue, ordered such that the `BinaryHeap` pops the lowest estimate first
+/// without requiring the nodes themselves to be ordered.
+struct State<N> {
+    estimate: Cost,
+    cost: Cost,
+    node: N,
+}
+
+impl<N> PartialEq for State<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.estimate == other.estimate
+    }
+}
+
+impl<N> Eq for State<N> {}
+
+impl<N> PartialOrd for State<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N> Ord for State<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.cmp(&self.estimate)
+    }
+}
+
+/// Breadth first search from multiple sources at once where every edge has a cost of one.
+pub fn multi_source_bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+) -> Search<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if search.distances.insert(start.clone(), 0).is_none() {
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        let distance = search.distances[&node];
+
+        for neighbour in neighbours(&node) {
+            if search.distances.contains_key(&neighbour) {
+                continue;
+            }
+
+            search.distances.insert(neighbour.clone(), distance + 1);
+            search.parents.insert(neighbour.clone(), node.clone());
+            queue.push_back(neighbour);
+        }
+    }
+
+    search
+}
+
+pub fn bfs<N, I>(start: N, neighbours: impl FnMut(&N) -> I) -> Search<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    multi_source_bfs([start], neighbours)
+}
+
+/// All nodes that can be reached from `start`, including `start` itself.
+pub fn reachable<N, I>(start: N, neighbours: impl FnMut(&N) -> I) -> HashSet<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    bfs(start, neighbours).reachable()
+}
+
+/// Best first search shared by Dijkstra and A*. Stops as soon as a node satisfying `is_goal` is
+/// taken from the queue and returns it along with the search state up to that point.
+fn best_first<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    heuristic: impl Fn(&N) -> Cost,
+    is_goal: impl Fn(&N) -> bool,
+) -> (Search<N>, Option<N>)
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, Cost)>,
+{
+    let mut search = Search::new();
+    let mut queue = BinaryHeap::new();
+
+    for start in starts {
+        search.distances.insert(start.clone(), 0);
+        queue.push(State {
+            estimate: heuristic(&start),
+            cost: 0,
+            node: start,
+        });
+    }
+
+    while let Some(State { cost, node, .. }) = queue.pop() {
+        if cost > search.distances[&node] {
+            continue;
+        }
+
+        if is_goal(&node) {
+            return (search, Some(node));
+        }
+
+        for (neighbour, step) in neighbours(&node) {
+            let cost = cost + step;
+
+            if search
+                .distances
+                .get(&neighbour)
+                .is_some_and(|known| *known <= cost)
+            {
+                continue;
+            }
+
+            search.distances.insert(neighbour.clone(), cost);
+            search.parents.insert(neighbour.clone(), node.clone());
+            queue.push(State {
+                estimate: cost + heuristic(&neighbour),
+                cost,
+                node: neighbour,
+            });
+        }
+    }
+
+    (search, None)
+}
+
+pub fn multi_source_dijkstra<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+) -> Search<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, Cost)>,
+{
+    best_first(starts, neighbours, |_| 0, |_| false).0
+}
+
+pub fn dijkstra<N, I>(start: N, neighbours: impl FnMut(&N) -> I) -> Search<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, Cost)>,
+{
+    multi_source_dijkstra([start], neighbours)
+}
+
+/// The cost and path to the closest node satisfying `is_goal`. The `heuristic` must never
+/// overestimate the remaining cost, use `|_| 0` to fall back to Dijkstra.
+pub fn astar<N, I>(
+    start: N,
+    is_goal: impl Fn(&N) -> bool,
+    neighbours: impl FnMut(&N) -> I,
+    heuristic: impl Fn(&N) -> Cost,
+) -> Option<(Cost, Vec<N>)>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, Cost)>,
+{
+    let (search, goal) = best_first([start], neighbours, heuristic, is_goal);
+    let goal = goal?;
+
+    Some((search.distance(&goal)?, search.path(&goal)?))
+}
+
+pub fn shortest_path<N, I>(
+    start: N,
+    is_goal: impl Fn(&N) -> bool,
+    neighbours: impl FnMut(&N) -> I,
+) -> Option<(Cost, Vec<N>)>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, Cost)>,
+{
+    astar(start, is_goal, neighbours, |_| 0)
+}
+
+/// The distances between every ordered pair of distinct points of interest that can reach each
+/// other, found with one Dijkstra run per point.
+pub fn all_pairs_distances<N, I>(
+    points: &[N],
+    mut neighbours: impl FnMut(&N) -> I,
+) -> HashMap<(N, N), Cost>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, Cost)>,
+{
+    points
+        .iter()
+        .flat_map(|source| {
+            let search = dijkstra(source.clone(), &mut neighbours);
+
+            points
+                .iter()
+                .filter(|target| *target != source)
+                .filter_map(|target| {
+                    search
+                        .distance(target)
+                        .map(|distance| ((source.clone(), target.clone()), distance))
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::{Grid, Point};
+    use itertools::Itertools;
+
+    const MAZE: &str = "\
+#########
+#A..#..B#
+#.#.#.#.#
+#.#...#.#
+#C#####D#
+#########";
+
+    fn open_neighbours(grid: &Grid<char>) -> impl Fn(&Point) -> Vec<(Point, Cost)> + '_ {
+        |point| {
+            grid.neighbours(*point)
+                .filter(|(_, value)| **value != '#')
+                .map(|(neighbour, _)| (neighbour, 1))
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_bfs_on_grid() {
+        let grid = Grid::parse(MAZE, |value| value);
+        let start = grid.find(|value| *value == 'A').unwrap();
+        let search = bfs(start, |point| {
+            open_neighbours(&grid)(point)
+                .into_iter()
+                .map(|(neighbour, _)| neighbour)
+        });
+
+        assert_eq!(
+            search.distance(&grid.find(|value| *value == 'B').unwrap()),
+            Some(10)
+        );
+        assert_eq!(
+            search.distance(&grid.find(|value| *value == 'C').unwrap()),
+            Some(3)
+        );
+
+        let target = grid.find(|value| *value == 'D').unwrap();
+        let path = search.path(&target).unwrap();
+        assert_eq!(path.len(), 14);
+        assert_eq!(path.first(), Some(&start));
+        assert_eq!(path.last(), Some(&target));
+        assert!(path
+            .iter()
+            .tuple_windows()
+            .all(|(a, b)| a.manhattan_distance(b) == 1));
+    }
+
+    #[test]
+    fn test_dijkstra_weighted() {
+        let edges: HashMap<char, Vec<(char, Cost)>> = HashMap::from([
+            ('a', vec![('b', 7), ('c', 2)]),
+            ('c', vec![('b', 3), ('d', 10)]),
+            ('b', vec![('d', 1)]),
+        ]);
+        let neighbours = |node: &char| edges.get(node).cloned().unwrap_or_default();
+
+        let search = dijkstra('a', neighbours);
+        assert_eq!(search.distance(&'b'), Some(5));
+        assert_eq!(search.path(&'d'), Some(vec!['a', 'c', 'b', 'd']));
+        assert_eq!(search.distance(&'e'), None);
+
+        let search = multi_source_dijkstra(['a', 'b'], neighbours);
+        assert_eq!(search.distance(&'d'), Some(1));
+    }
+
+    #[test]
+    fn test_astar_matches_dijkstra() {
+        let grid = Grid::parse(MAZE, |value| value);
+        let start = grid.find(|value| *value == 'A').unwrap();
+        let goal = grid.find(|value| *value == 'D').unwrap();
+
+        let (cost, path) = astar(
+            start,
+            |point| *point == goal,
+            open_neighbours(&grid),
+            |point| point.manhattan_distance(&goal) as Cost,
+        )
+        .unwrap();
+
+        assert_eq!(cost, 13);
+        assert_eq!(path.len(), 14);
+        assert_eq!(
+            shortest_path(start, |point| *point == goal, open_neighbours(&grid)).map(|r| r.0),
+            Some(13)
+        );
+    }
+
+    #[test]
+    fn test_reachable_and_all_pairs() {
+        let grid = Grid::parse(MAZE, |value| value);
+        let start = grid.find(|value| *value == 'A').unwrap();
+        let open = grid.iter().filter(|(_, value)| **value != '#').count();
+
+        let reachable = reachable(start, |point| {
+            open_neighbours(&grid)(point)
+                .into_iter()
+                .map(|(neighbour, _)| neighbour)
+        });
+        assert_eq!(reachable.len(), open);
+
+        let points = ['A', 'B', 'C'].map(|label| grid.find(|value| *value == label).unwrap());
+        let distances = all_pairs_distances(&points, open_neighbours(&grid));
+
+        assert_eq!(distances.len(), 6);
+        assert_eq!(distances[&(points[1], points[2])], 13);
+        assert_eq!(distances[&(points[2], points[1])], 13);
+    }
+}
@@ -0,0 +1,233 @@
+use crate::helpers::math::{gcd, mod_inverse, mul_mod};
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ShuffleError {
+    InvalidLine(String),
+    /// Dealing with an increment that shares a factor with the deck size puts several cards on
+    /// the same position, so the shuffle would not be a permutation.
+    SharedFactor {
+        increment: i64,
+        size: i64,
+    },
+}
+
+impl Display for ShuffleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ShuffleError::InvalidLine(line) => write!(f, "'{line}' is not a shuffle technique."),
+            ShuffleError::SharedFactor { increment, size } => write!(
+                f,
+                "dealing with increment {increment} does not shuffle a deck of {size} cards."
+            ),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Technique {
+    DealIntoNewStack,
+    Cut(i64),
+    DealWithIncrement(i64),
+}
+
+impl Technique {
+    pub fn parse(line: &str) -> Result<Self, ShuffleError> {
+        let line = line.trim();
+        let invalid = || ShuffleError::InvalidLine(line.to_string());
+
+        if line == "deal into new stack" {
+            return Ok(Technique::DealIntoNewStack);
+        }
+
+        if let Some(amount) = line.strip_prefix("cut ") {
+            return amount.parse().map(Technique::Cut).map_err(|_| invalid());
+        }
+
+        if let Some(increment) = line.strip_prefix("deal with increment ") {
+            return increment
+                .parse()
+                .map(Technique::DealWithIncrement)
+                .map_err(|_| invalid());
+        }
+
+        Err(invalid())
+    }
+}
+
+/// A shuffle of a deck of `size` cards as the affine map that sends the card at `position` to
+/// `multiplier * position + offset` modulo `size`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Shuffle {
+    multiplier: i64,
+    offset: i64,
+    size: i64,
+}
+
+impl Shuffle {
+    /// The shuffle that leaves every card in place.
+    pub fn identity(size: i64) -> Self {
+        Self {
+            multiplier: 1,
+            offset: 0,
+            size,
+        }
+    }
+
+    pub fn from_technique(technique: Technique, size: i64) -> Result<Self, ShuffleError> {
+        let (multiplier, offset) = match technique {
+            Technique::DealIntoNewStack => (-1, -1),
+            Technique::Cut(amount) => (1, -amount),
+            Technique::DealWithIncrement(increment) => {
+                if gcd(increment, size) != 1 {
+                    return Err(ShuffleError::SharedFactor { increment, size });
+                }
+
+                (increment, 0)
+            }
+        };
+
+        Ok(Self {
+            multiplier: multiplier.rem_euclid(size),
+            offset: offset.rem_euclid(size),
+            size,
+        })
+    }
+
+    /// The shuffle that applies all techniques in the input, one per line, in order.
+    pub fn from_input(input: &str, size: i64) -> Result<Self, ShuffleError> {
+        input
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .try_fold(Self::identity(size), |shuffle, line| {
+                Ok(shuffle.then(&Self::from_technique(Technique::parse(line)?, size)?))
+            })
+    }
+
+    pub fn size(&self) -> i64 {
+        self.size
+    }
+
+    /// The shuffle that first applies `self` and then `other`.
+    pub fn then(&self, other: &Shuffle) -> Self {
+        assert_eq!(self.size, other.size, "Shuffles must use the same deck");
+
+        Self {
+            multiplier: mul_mod(other.multiplier, self.multiplier, self.size),
+            offset: (mul_mod(other.multiplier, self.offset, self.size) + other.offset)
+                .rem_euclid(self.size),
+            size: self.size,
+        }
+    }
+
+    /// The shuffle applied `times` times in a row, by repeated squaring.
+    pub fn repeat(&self, mut times: u64) -> Self {
+        let mut result = Self::identity(self.size);
+        let mut square = *self;
+
+        while times > 0 {
+            if times & 1 == 1 {
+                result = result.then(&square);
+            }
+
+            square = square.then(&square);
+            times >>= 1;
+        }
+
+        result
+    }
+
+    /// The shuffle that puts every card back where it was before this one.
+    pub fn inverse(&self) -> Self {
+        let multiplier = mod_inverse(self.multiplier, self.size)
+            .expect("A shuffle is a permutation so its multiplier is invertible");
+
+        Self {
+            multiplier,
+            offset: mul_mod(-self.offset, multiplier, self.size),
+            size: self.size,
+        }
+    }
+
+    /// The position that `card` ends up at, for a deck that starts in factory order.
+    pub fn position_of(&self, card: i64) -> i64 {
+        (mul_mod(self.multiplier, card, self.size) + self.offset).rem_euclid(self.size)
+    }
+
+    /// The card that ends up at `position`, for a deck that starts in factory order.
+    pub fn card_at(&self, position: i64) -> i64 {
+        self.inverse().position_of(position)
+    }
+
+    /// The whole deck after shuffling one in factory order, only feasible for small decks.
+    pub fn deck(&self) -> Vec<i64> {
+        let inverse = self.inverse();
+
+        (0..self.size)
+            .map(|position| inverse.position_of(position))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn deck(input: &str) -> Vec<i64> {
+        Shuffle::from_input(input, 10).unwrap().deck()
+    }
+
+    #[test]
+    fn test_techniques() {
+        assert_eq!(
+            deck("deal into new stack"),
+            vec![9, 8, 7, 6, 5, 4, 3, 2, 1, 0]
+        );
+        assert_eq!(deck("cut 3"), vec![3, 4, 5, 6, 7, 8, 9, 0, 1, 2]);
+        assert_eq!(deck("cut -4"), vec![6, 7, 8, 9, 0, 1, 2, 3, 4, 5]);
+        assert_eq!(
+            deck("deal with increment 3"),
+            vec![0, 7, 4, 1, 8, 5, 2, 9, 6, 3]
+        );
+    }
+
+    #[test]
+    fn test_composition() {
+        assert_eq!(
+            deck("cut 6\ndeal with increment 7\ndeal into new stack"),
+            vec![3, 0, 7, 4, 1, 8, 5, 2, 9, 6]
+        );
+        assert_eq!(
+            deck("deal with increment 7\ndeal with increment 9\ncut -2"),
+            vec![6, 3, 0, 7, 4, 1, 8, 5, 2, 9]
+        );
+    }
+
+    #[test]
+    fn test_repeat_and_inverse() {
+        let shuffle = Shuffle::from_input("cut 6\ndeal with increment 7", 11).unwrap();
+        let repeated = (0..5).fold(Shuffle::identity(11), |result, _| result.then(&shuffle));
+
+        assert_eq!(shuffle.repeat(5), repeated);
+        assert_eq!(shuffle.then(&shuffle.inverse()), Shuffle::identity(11));
+
+        for card in 0..11 {
+            assert_eq!(repeated.card_at(repeated.position_of(card)), card);
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Shuffle::from_input("cut the deck", 10),
+            Err(ShuffleError::InvalidLine("cut the deck".to_string()))
+        );
+        assert_eq!(
+            Shuffle::from_input("deal with increment 4", 10),
+            Err(ShuffleError::SharedFactor {
+                increment: 4,
+                size: 10
+            })
+        );
+    }
+}
@@ -0,0 +1,351 @@
+use crate::helpers::ocr::{self, OcrError};
+use crate::helpers::{Grid, Point, Unit};
+use std::fmt::Display;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Pixel {
+    Black,
+    White,
+    Transparent,
+}
+
+impl Pixel {
+    fn from_digit(digit: u8) -> Option<Self> {
+        match digit {
+            0 => Some(Pixel::Black),
+            1 => Some(Pixel::White),
+            2 => Some(Pixel::Transparent),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum SpaceImageError {
+    InvalidDigit(char),
+    /// Only `0`, `1` and `2` are colors, other digits can be counted but not drawn.
+    InvalidColor(u8),
+    IncompleteLayer {
+        digits: usize,
+        layer_size: usize,
+    },
+}
+
+impl Display for SpaceImageError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SpaceImageError::InvalidDigit(value) => write!(f, "'{value}' is not a digit."),
+            SpaceImageError::InvalidColor(digit) => write!(f, "'{digit}' is not a color."),
+            SpaceImageError::IncompleteLayer { digits, layer_size } => write!(
+                f,
+                "{digits} digits cannot be split into layers of {layer_size} digits."
+            ),
+        }
+    }
+}
+
+/// An image in the Space Image Format: a stack of `width` by `height` layers of digits, with the
+/// first layer in front.
+#[derive(Clone, Debug)]
+pub struct SpaceImage {
+    width: usize,
+    height: usize,
+    layers: Vec<Vec<u8>>,
+}
+
+impl SpaceImage {
+    pub fn from_input(input: &str, width: usize, height: usize) -> Result<Self, SpaceImageError> {
+        let digits = input
+            .trim()
+            .chars()
+            .map(|value| {
+                value
+                    .to_digit(10)
+                    .map(|digit| digit as u8)
+                    .ok_or(SpaceImageError::InvalidDigit(value))
+            })
+            .collect::<Result<Vec<u8>, _>>()?;
+
+        let layer_size = width * height;
+        if layer_size == 0 || digits.len() % layer_size != 0 {
+            return Err(SpaceImageError::IncompleteLayer {
+                digits: digits.len(),
+                layer_size,
+            });
+        }
+
+        Ok(Self {
+            width,
+            height,
+            layers: digits
+                .chunks(layer_size)
+                .map(|layer| layer.to_vec())
+                .collect(),
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn layers(&self) -> &[Vec<u8>] {
+        &self.layers
+    }
+
+    /// How often every digit occurs in the given layer, indexed by digit.
+    pub fn histogram(&self, layer: usize) -> [usize; 10] {
+        self.layers[layer]
+            .iter()
+            .fold([0; 10], |mut histogram, digit| {
+                histogram[*digit as usize] += 1;
+                histogram
+            })
+    }
+
+    pub fn histograms(&self) -> Vec<[usize; 10]> {
+        (0..self.layers.len())
+            .map(|layer| self.histogram(layer))
+            .collect()
+    }
+
+    /// Stacks the layers, every pixel takes the color of the first layer that is not transparent
+    /// at its position. Fails when any layer contains a digit that is not a color.
+    pub fn composite(&self) -> Result<Vec<Pixel>, SpaceImageError> {
+        (0..self.width * self.height)
+            .map(|index| {
+                let pixels = self
+                    .layers
+                    .iter()
+                    .map(|layer| {
+                        Pixel::from_digit(layer[index])
+                            .ok_or(SpaceImageError::InvalidColor(layer[index]))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                Ok(pixels
+                    .into_iter()
+                    .find(|pixel| *pixel != Pixel::Transparent)
+                    .unwrap_or(Pixel::Transparent))
+            })
+            .collect()
+    }
+
+    /// The composited image as rows of pixels.
+    pub fn rows(&self) -> Result<Vec<Vec<Pixel>>, SpaceImageError> {
+        Ok(self
+            .composite()?
+            .chunks(self.width)
+            .map(|row| row.to_vec())
+            .collect())
+    }
+
+    pub fn render<R: Renderer>(&self, renderer: &R) -> Result<R::Output, SpaceImageError> {
+        Ok(renderer.render(&self.rows()?))
+    }
+}
+
+/// Turns the rows of the composited image into some other representation.
+pub trait Renderer {
+    type Output;
+
+    fn render(&self, rows: &[Vec<Pixel>]) -> Self::Output;
+}
+
+fn size(rows: &[Vec<Pixel>]) -> (usize, usize) {
+    (rows.first().map_or(0, |row| row.len()), rows.len())
+}
+
+/// Renders the image as lines of text, transparent pixels are shown as spaces.
+pub struct TextRenderer {
+    pub black: char,
+    pub white: char,
+}
+
+impl Default for TextRenderer {
+    fn default() -> Self {
+        Self {
+            black: ' ',
+            white: '█',
+        }
+    }
+}
+
+impl Renderer for TextRenderer {
+    type Output = String;
+
+    fn render(&self, rows: &[Vec<Pixel>]) -> String {
+        rows.iter()
+            .map(|row| {
+                row.iter()
+                    .map(|pixel| match pixel {
+                        Pixel::Black => self.black,
+                        Pixel::White => self.white,
+                        Pixel::Transparent => ' ',
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Renders the image as a plain (ASCII) portable bitmap. Only white pixels are drawn, as `0`.
+pub struct PbmRenderer;
+
+impl Renderer for PbmRenderer {
+    type Output = String;
+
+    fn render(&self, rows: &[Vec<Pixel>]) -> String {
+        let (width, height) = size(rows);
+        let lines = rows
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|pixel| match pixel {
+                        Pixel::White => "0",
+                        _ => "1",
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .collect::<Vec<_>>();
+
+        format!("P1\n{} {}\n{}\n", width, height, lines.join("\n"))
+    }
+}
+
+/// Renders the image as a plain (ASCII) portable graymap, transparent pixels are gray.
+pub struct PgmRenderer;
+
+impl Renderer for PgmRenderer {
+    type Output = String;
+
+    fn render(&self, rows: &[Vec<Pixel>]) -> String {
+        let (width, height) = size(rows);
+        let lines = rows
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|pixel| match pixel {
+                        Pixel::Black => "0",
+                        Pixel::White => "2",
+                        Pixel::Transparent => "1",
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .collect::<Vec<_>>();
+
+        format!("P2\n{} {}\n2\n{}\n", width, height, lines.join("\n"))
+    }
+}
+
+/// Reads the white pixels of the image as text written in one of the letter fonts.
+pub struct OcrRenderer;
+
+impl Renderer for OcrRenderer {
+    type Output = Result<String, OcrError>;
+
+    fn render(&self, rows: &[Vec<Pixel>]) -> Result<String, OcrError> {
+        let (width, height) = size(rows);
+        let mut grid = Grid::new(width, height, false);
+
+        for (y, row) in rows.iter().enumerate() {
+            for (x, pixel) in row.iter().enumerate() {
+                grid.set(Point::new(x as Unit, y as Unit), *pixel == Pixel::White);
+            }
+        }
+
+        ocr::recognize(&grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_histograms() {
+        let image = SpaceImage::from_input("123456789012", 3, 2).unwrap();
+
+        assert_eq!(image.layers().len(), 2);
+        assert_eq!(image.histogram(0), [0, 1, 1, 1, 1, 1, 1, 0, 0, 0]);
+        assert_eq!(image.histograms()[1], [1, 1, 1, 0, 0, 0, 0, 1, 1, 1]);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            SpaceImage::from_input("12a4", 2, 2).unwrap_err(),
+            SpaceImageError::InvalidDigit('a')
+        );
+        assert_eq!(
+            SpaceImage::from_input("12345", 2, 2).unwrap_err(),
+            SpaceImageError::IncompleteLayer {
+                digits: 5,
+                layer_size: 4
+            }
+        );
+    }
+
+    #[test]
+    fn test_composite_and_render() {
+        let image = SpaceImage::from_input("0222112222120000", 2, 2).unwrap();
+
+        assert_eq!(
+            image.composite(),
+            Ok(vec![Pixel::Black, Pixel::White, Pixel::White, Pixel::Black])
+        );
+        assert_eq!(
+            image.render(&TextRenderer {
+                black: '.',
+                white: '#'
+            }),
+            Ok(".#\n#.".to_string())
+        );
+        assert_eq!(
+            image.render(&PbmRenderer),
+            Ok("P1\n2 2\n1 0\n0 1\n".to_string())
+        );
+        assert_eq!(
+            image.render(&PgmRenderer),
+            Ok("P2\n2 2\n2\n0 2\n2 0\n".to_string())
+        );
+    }
+
+    #[test]
+    fn test_invalid_color() {
+        let image = SpaceImage::from_input("02221272", 2, 2).unwrap();
+
+        assert_eq!(image.histogram(1)[7], 1);
+        assert_eq!(image.composite(), Err(SpaceImageError::InvalidColor(7)));
+        assert_eq!(
+            image.render(&PbmRenderer),
+            Err(SpaceImageError::InvalidColor(7))
+        );
+    }
+
+    #[test]
+    fn test_ocr() {
+        let letters = [
+            "#..#.####.",
+            "#..#.#....",
+            "####.###..",
+            "#..#.#....",
+            "#..#.#....",
+            "#..#.####.",
+        ];
+        let digits = letters
+            .concat()
+            .chars()
+            .map(|value| if value == '#' { '1' } else { '0' })
+            .collect::<String>();
+        let image = SpaceImage::from_input(&digits, 10, 6).unwrap();
+
+        assert_eq!(image.render(&OcrRenderer), Ok(Ok("HE".to_string())));
+    }
+}
@@ -0,0 +1,261 @@
+use crate::helpers::search::{self, Cost};
+use crate::helpers::{Grid, Point};
+use std::collections::HashMap;
+
+/// Bitmask with one bit per key, bit `0` being key `a`.
+pub type KeySet = u32;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Tile {
+    Wall,
+    Open,
+    Entrance,
+    Key(u8),
+    Door(u8),
+}
+
+impl Tile {
+    fn from_char(value: char) -> Self {
+        match value {
+            '#' => Tile::Wall,
+            '.' => Tile::Open,
+            '@' => Tile::Entrance,
+            'a'..='z' => Tile::Key(value as u8 - b'a'),
+            'A'..='Z' => Tile::Door(value as u8 - b'A'),
+            _ => panic!("Unknown vault tile: '{value}'"),
+        }
+    }
+
+    fn to_char(self) -> char {
+        match self {
+            Tile::Wall => '#',
+            Tile::Open => '.',
+            Tile::Entrance => '@',
+            Tile::Key(key) => (b'a' + key) as char,
+            Tile::Door(door) => (b'A' + door) as char,
+        }
+    }
+}
+
+/// A path between two points of interest in the vault.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+struct Edge {
+    target: usize,
+    distance: Cost,
+    /// The doors on the way, and the keys passed by which are picked up first.
+    required: KeySet,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct State {
+    robots: Vec<usize>,
+    collected: KeySet,
+}
+
+pub struct Vault {
+    grid: Grid<Tile>,
+}
+
+impl Vault {
+    pub fn from_input(input: &str) -> Self {
+        Self {
+            grid: Grid::parse(input.trim_end(), Tile::from_char),
+        }
+    }
+
+    pub fn entrances(&self) -> Vec<Point> {
+        self.grid
+            .iter()
+            .filter(|(_, tile)| **tile == Tile::Entrance)
+            .map(|(point, _)| point)
+            .collect()
+    }
+
+    /// Replaces the area around the single entrance such that the vault is split into four
+    /// quadrants, each with its own entrance.
+    pub fn split_entrance(&mut self) {
+        let entrances = self.entrances();
+        assert_eq!(
+            entrances.len(),
+            1,
+            "Only a vault with one entrance can be split"
+        );
+        let center = entrances[0];
+
+        for point in center.neighbours_with_diagonals() {
+            let offset = point - center;
+            let tile = match offset.x != 0 && offset.y != 0 {
+                true => Tile::Entrance,
+                false => Tile::Wall,
+            };
+
+            self.grid.set(point, tile);
+        }
+
+        self.grid.set(center, Tile::Wall);
+    }
+
+    /// The entrances followed by the keys sorted by letter, which are the nodes of the compressed
+    /// graph.
+    fn points_of_interest(&self) -> Vec<Point> {
+        let mut keys = self
+            .grid
+            .iter()
+            .filter_map(|(point, tile)| match tile {
+                Tile::Key(key) => Some((*key, point)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        keys.sort();
+
+        self.entrances()
+            .into_iter()
+            .chain(keys.into_iter().map(|(_, point)| point))
+            .collect()
+    }
+
+    /// Compresses the vault into the paths from every point of interest to every reachable key,
+    /// remembering which keys are needed to walk each path.
+    fn compress(&self, points: &[Point]) -> Vec<Vec<Edge>> {
+        let index = points
+            .iter()
+            .enumerate()
+            .map(|(index, point)| (*point, index))
+            .collect::<HashMap<Point, usize>>();
+
+        points
+            .iter()
+            .map(|source| {
+                let search = search::bfs(*source, |point| {
+                    self.grid
+                        .neighbours(*point)
+                        .filter(|(_, tile)| **tile != Tile::Wall)
+                        .map(|(neighbour, _)| neighbour)
+                        .collect::<Vec<_>>()
+                });
+
+                search
+                    .distances()
+                    .iter()
+                    .filter(|(target, _)| *target != source)
+                    .filter_map(|(target, distance)| {
+                        let Tile::Key(_) = self.grid.get(target)? else {
+                            return None;
+                        };
+
+                        let path = search.path(target)?;
+                        let required = path[1..path.len() - 1].iter().fold(0, |required, point| {
+                            match self.grid.get(point) {
+                                Some(Tile::Key(key)) | Some(Tile::Door(key)) => required | 1 << key,
+                                _ => required,
+                            }
+                        });
+
+                        Some(Edge {
+                            target: index[target],
+                            distance: *distance,
+                            required,
+                        })
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// The fewest steps needed for the robots, one per entrance, to collect every key.
+    pub fn shortest_path_to_collect_all_keys(&self) -> Option<Cost> {
+        let points = self.points_of_interest();
+        let edges = self.compress(&points);
+
+        let robots = self.entrances().len();
+        let all_keys = (0..points.len() - robots).fold(0, |keys, key| keys | 1 << key);
+        let key_of = |node: usize| 1 << (node - robots);
+
+        let start = State {
+            robots: (0..robots).collect(),
+            collected: 0,
+        };
+
+        search::shortest_path(
+            start,
+            |state| state.collected == all_keys,
+            |state| {
+                state
+                    .robots
+                    .iter()
+                    .enumerate()
+                    .flat_map(|(robot, node)| {
+                        edges[*node]
+                            .iter()
+                            .filter(|edge| state.collected & key_of(edge.target) == 0)
+                            .filter(|edge| edge.required & !state.collected == 0)
+                            .map(move |edge| (robot, edge))
+                    })
+                    .map(|(robot, edge)| {
+                        let mut robots = state.robots.clone();
+                        robots[robot] = edge.target;
+
+                        let collected = state.collected | key_of(edge.target);
+                        (State { robots, collected }, edge.distance)
+                    })
+                    .collect::<Vec<_>>()
+            },
+        )
+        .map(|(steps, _)| steps)
+    }
+
+    pub fn render(&self) -> String {
+        self.grid
+            .render(|tile| tile.map_or(' ', |tile| tile.to_char()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_single_robot() {
+        let vault = Vault::from_input("#########\n#b.A.@.a#\n#########");
+        assert_eq!(vault.shortest_path_to_collect_all_keys(), Some(8));
+
+        let vault = Vault::from_input(
+            "#################
+#i.G..c...e..H.p#
+########.########
+#j.A..b...f..D.o#
+########@########
+#k.E..a...g..B.n#
+########.########
+#l.F..d...h..C.m#
+#################",
+        );
+        assert_eq!(vault.shortest_path_to_collect_all_keys(), Some(136));
+    }
+
+    #[test]
+    fn test_split_entrance() {
+        let mut vault = Vault::from_input(
+            "#######
+#a.#Cd#
+##...##
+##.@.##
+##...##
+#cB#Ab#
+#######",
+        );
+        vault.split_entrance();
+
+        assert_eq!(
+            vault.render(),
+            "#######
+#a.#Cd#
+##@#@##
+#######
+##@#@##
+#cB#Ab#
+#######"
+        );
+        assert_eq!(vault.shortest_path_to_collect_all_keys(), Some(8));
+    }
+}
@@ -5,129 +5,188 @@
  */
 use std::env;
 use std::fs;
+use std::path::PathBuf;
 
+pub mod answers;
+pub mod aoc_client;
+pub mod bench;
+pub mod examples;
+pub mod extract;
 pub mod helpers;
+pub mod markdown;
+pub mod records;
+pub mod runner;
+pub mod submissions;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
+/// Runs both parts of a day with `solve!(part_one, part_two, input)`, exiting with an error after
+/// both ran when either answer is wrong. `solve!(1, part_one, input)` runs one part and evaluates
+/// to its [`Status`](records::Status). Invalid arguments exit with the usage before anything runs.
 #[macro_export]
 macro_rules! solve {
-    ($part:expr, $solver:ident, $input:expr) => {{
-        use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-        use std::fmt::Display;
+    (@options) => {
+        $crate::bench::Options::from_env()
+            .unwrap_or_else(|e| $crate::bench::exit_with_usage(e, $crate::bench::SOLVE_USAGE))
+    };
+    ($part_one:ident, $part_two:ident, $input:expr) => {{
+        let options = $crate::solve!(@options);
+        let input = $input;
+        let statuses = [
+            $crate::solve!(@run options, 1, $part_one, input),
+            $crate::solve!(@run options, 2, $part_two, input),
+        ];
+
+        if statuses.contains(&$crate::records::Status::Wrong) {
+            std::process::exit(1);
+        }
+    }};
+    (@run $options:ident, $part:expr, $solver:ident, $input:expr) => {{
+        use advent_of_code::answers::Answers;
+        use advent_of_code::bench;
+        use advent_of_code::records::{self, Record};
+        use advent_of_code::runner::{self, Answer, PartResult};
         use std::time::Instant;
 
-        fn print_result<T: Display>(func: impl FnOnce(&str) -> Option<T>, input: &str) {
+        fn run<T: Into<Answer>>(
+            part: u8,
+            func: impl FnOnce(&str) -> Option<T>,
+            input: &str,
+        ) -> PartResult {
             let timer = Instant::now();
-            let result = func(input);
-            let elapsed = timer.elapsed();
-            match result {
-                Some(result) => {
-                    println!(
-                        "{} {}(elapsed: {:.2?}){}",
-                        result, ANSI_ITALIC, elapsed, ANSI_RESET
-                    );
-                }
-                None => {
-                    println!("not solved.")
+            let answer = func(input);
+            let duration = timer.elapsed();
+
+            PartResult {
+                part,
+                answer: answer.map(Into::into),
+                duration,
+            }
+        }
+
+        let options = &$options;
+        let (year, day) = advent_of_code::parse_bin_name(env!("CARGO_BIN_NAME")).unwrap_or((0, 0));
+        let answers = Answers::load(year, day);
+
+        let result = if options.bench {
+            let (result, measurement) = bench::report(year, day, $part, $solver, $input, &answers);
+            bench::report_change(&measurement, options);
+
+            if let Some(path) = &options.save_baseline {
+                if let Err(e) = bench::save_baseline(path, &[measurement]) {
+                    eprintln!("Failed to save baseline: {e}");
                 }
             }
+
+            result
+        } else {
+            let result = run($part, $solver, $input);
+            runner::print_result(&result, &answers);
+            result
+        };
+
+        let status = answers.check($part, result.answer.as_ref());
+        if let Err(e) = records::emit(&Record::new(year, day, &result, status)) {
+            eprintln!("Failed to write record: {e}");
         }
 
-        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
-        print_result($solver, $input);
+        status
     }};
+    ($part:expr, $solver:ident, $input:expr) => {{
+        let options = $crate::solve!(@options);
+        $crate::solve!(@run options, $part, $solver, $input)
+    }};
+}
+
+/// The environment variable that holds the year binaries use when none is given, set in
+/// `.cargo/config`.
+pub const YEAR_ENV: &str = "AOC_YEAR";
+
+/// The configured default year, read when running and otherwise when building. Falls back to the
+/// latest year in `src` when `AOC_YEAR` is missing or not a year, as it is for binaries that run
+/// outside cargo.
+pub fn default_year() -> Option<u16> {
+    env::var(YEAR_ENV)
+        .ok()
+        .or(option_env!("AOC_YEAR").map(String::from))
+        .and_then(|year| year.parse().ok())
+        .or_else(latest_year)
+}
+
+/// The latest year that has a directory in `src`.
+pub fn latest_year() -> Option<u16> {
+    fs::read_dir(env::current_dir().ok()?.join("src"))
+        .ok()?
+        .filter_map(|entry| entry.ok()?.file_name().to_str()?.parse().ok())
+        .max()
+}
+
+/// The `--year/-y` argument, or the [default year](default_year) when it is not given.
+pub fn parse_year(args: &mut pico_args::Arguments) -> Result<u16, pico_args::Error> {
+    args.opt_value_from_str(["-y", "--year"])?
+        .or_else(default_year)
+        .ok_or(pico_args::Error::MissingOption(["-y", "--year"].into()))
+}
+
+/// A `--part/-p` value, which is either 1 or 2.
+pub fn parse_part(value: &str) -> Result<u8, String> {
+    match value.parse() {
+        Ok(part @ 1..=2) => Ok(part),
+        _ => Err("expected part 1 or 2".to_string()),
+    }
+}
+
+/// The year and day of a solution binary named `YYYY-DD`.
+pub fn parse_bin_name(name: &str) -> Option<(u16, u8)> {
+    let (year, day) = name.split_once('-')?;
+    Some((year.parse().ok()?, day.parse().ok()?))
 }
 
-pub fn read_file(folder: &str, day: u8) -> String {
+/// The directory holding the solutions and data of a year, `src/YYYY`.
+pub fn year_path(year: u16) -> PathBuf {
     let cwd = env::current_dir().unwrap();
+    cwd.join("src").join(year.to_string())
+}
 
-    let filepath = cwd.join("src").join(folder).join(format!("{day:02}.txt"));
+pub fn read_file(folder: &str, year: u16, day: u8) -> String {
+    let filepath = year_path(year).join(folder).join(format!("{day:02}.txt"));
 
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
-fn parse_time(val: &str, postfix: &str) -> f64 {
-    val.split(postfix).next().unwrap().parse().unwrap()
+/// Like [`read_file`], but `None` when the file does not exist.
+pub fn try_read_file(folder: &str, year: u16, day: u8) -> Option<String> {
+    let filepath = year_path(year).join(folder).join(format!("{day:02}.txt"));
+
+    fs::read_to_string(filepath).ok()
 }
 
-pub fn parse_exec_time(output: &str) -> f64 {
-    output.lines().fold(0_f64, |acc, l| {
-        if !l.contains("elapsed:") {
-            acc
-        } else {
-            let timing = l.split("(elapsed: ").last().unwrap();
-            // use `contains` istd. of `ends_with`: string may contain ANSI escape sequences.
-            // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-            if timing.contains("ns)") {
-                acc // range below rounding precision.
-            } else if timing.contains("µs)") {
-                acc + parse_time(timing, "µs") / 1000_f64
-            } else if timing.contains("ms)") {
-                acc + parse_time(timing, "ms")
-            } else if timing.contains("s)") {
-                acc + parse_time(timing, "s") * 1000_f64
-            } else {
-                acc
-            }
-        }
-    })
+/// Reads the input of an example of the day, see [`examples::load`].
+pub fn read_example(year: u16, day: u8, number: usize) -> String {
+    examples::load(year, day, number).input
 }
 
-/// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-#[cfg(test)]
-macro_rules! assert_approx_eq {
-    ($a:expr, $b:expr) => {{
-        let (a, b) = (&$a, &$b);
-        assert!(
-            (*a - *b).abs() < 1.0e-6,
-            "{} is not approximately equal to {}",
-            *a,
-            *b
-        );
-    }};
+pub fn get_input_path(year: u16, day: u8) -> String {
+    let day_padded = format!("{day:02}");
+    format!("src/{year}/inputs/{day_padded}.txt")
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_parse_exec_time() {
-        assert_approx_eq!(
-            parse_exec_time(&format!(
-                "🎄 Part 1 🎄\n0 (elapsed: 74.13ns){}\n🎄 Part 2 🎄\n0 (elapsed: 50.00ns){}",
-                ANSI_RESET, ANSI_RESET
-            )),
-            0_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 755µs)\n🎄 Part 2 🎄\n0 (elapsed: 700µs)"),
-            1.455_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 70µs)\n🎄 Part 2 🎄\n0 (elapsed: 1.45ms)"),
-            1.52_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time(
-                "🎄 Part 1 🎄\n0 (elapsed: 10.3s)\n🎄 Part 2 🎄\n0 (elapsed: 100.50ms)"
-            ),
-            10400.50_f64
-        );
-    }
+pub fn get_puzzle_path(year: u16, day: u8) -> String {
+    let day_padded = format!("{day:02}");
+    format!("src/{year}/puzzles/{day_padded}.md")
+}
+
+pub fn get_module_path(year: u16, day: u8) -> String {
+    let day_padded = format!("{day:02}");
+    format!("src/{year}/{day_padded}.rs")
 }
 
 pub mod aoc_cli {
     use std::{
         fmt::Display,
-        fs::create_dir_all,
         process::{Command, Output, Stdio},
     };
 
@@ -135,7 +194,6 @@ pub mod aoc_cli {
         CommandNotFound,
         CommandNotCallable,
         BadExitStatus(Output),
-        IoError,
     }
 
     impl Display for AocCliError {
@@ -146,7 +204,6 @@ pub mod aoc_cli {
                 AocCliError::BadExitStatus(_) => {
                     write!(f, "aoc-cli exited with a non-zero status.")
                 }
-                AocCliError::IoError => write!(f, "could not write output files to file system."),
             }
         }
     }
@@ -160,52 +217,30 @@ pub mod aoc_cli {
     }
 
     pub fn read(day: u8, year: Option<u16>) -> Result<Output, AocCliError> {
-        // TODO: output local puzzle if present.
         let args = build_args("read", &[], day, year);
         call_aoc_cli(&args)
     }
 
-    pub fn download(day: u8, year: Option<u16>) -> Result<Output, AocCliError> {
-        let input_path = get_input_path(day);
-
-        let puzzle_path = get_puzzle_path(day);
-        create_dir_all("src/puzzles").map_err(|_| AocCliError::IoError)?;
+    /// Submits the answer and returns the output of aoc-cli, which holds the response.
+    pub fn submit(
+        day: u8,
+        part: u8,
+        answer: &str,
+        year: Option<u16>,
+    ) -> Result<Output, AocCliError> {
+        let mut args = build_args("submit", &[], day, year);
+        args.push(part.to_string());
+        args.push(answer.to_string());
 
-        let args = build_args(
-            "download",
-            &[
-                "--overwrite".into(),
-                "--input-file".into(),
-                input_path.to_string(),
-                "--puzzle-file".into(),
-                puzzle_path.to_string(),
-            ],
-            day,
-            year,
-        );
-
-        let output = call_aoc_cli(&args)?;
+        let output = call_aoc_cli_captured(&args)?;
 
         if output.status.success() {
-            println!("---");
-            println!("🎄 Successfully wrote input to \"{}\".", &input_path);
-            println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
             Ok(output)
         } else {
             Err(AocCliError::BadExitStatus(output))
         }
     }
 
-    fn get_input_path(day: u8) -> String {
-        let day_padded = format!("{day:02}");
-        format!("src/inputs/{day_padded}.txt")
-    }
-
-    fn get_puzzle_path(day: u8) -> String {
-        let day_padded = format!("{day:02}");
-        format!("src/puzzles/{day_padded}.md")
-    }
-
     fn build_args(command: &str, args: &[String], day: u8, year: Option<u16>) -> Vec<String> {
         let mut cmd_args = args.to_vec();
 
@@ -231,4 +266,18 @@ pub mod aoc_cli {
             .output()
             .map_err(|_| AocCliError::CommandNotCallable)
     }
+
+    /// Like `call_aoc_cli`, but keeps the standard output so it can be parsed.
+    fn call_aoc_cli_captured(args: &[String]) -> Result<Output, AocCliError> {
+        if cfg!(debug_assertions) {
+            println!("Calling >aoc with: {}", args.join(" "));
+        }
+
+        Command::new("aoc")
+            .args(args)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .output()
+            .map_err(|_| AocCliError::CommandNotCallable)
+    }
 }
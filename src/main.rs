@@ -2,44 +2,135 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use advent_of_code::answers::Answers;
+use advent_of_code::bench::{self, Change, Options};
+use advent_of_code::records::{self, Record, Status};
+use advent_of_code::runner::{self, PartResult};
 use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use std::process;
+
+// Every solution in `src/YYYY`, `cargo scaffold` adds new days here.
+advent_of_code::solutions! {
+    y2019_day01: 2019, 1 => "2019/01.rs",
+    y2019_day02: 2019, 2 => "2019/02.rs",
+    y2019_day03: 2019, 3 => "2019/03.rs",
+    y2019_day04: 2019, 4 => "2019/04.rs",
+    y2019_day05: 2019, 5 => "2019/05.rs",
+    y2019_day06: 2019, 6 => "2019/06.rs",
+    y2019_day07: 2019, 7 => "2019/07.rs",
+    y2019_day08: 2019, 8 => "2019/08.rs",
+    y2019_day09: 2019, 9 => "2019/09.rs",
+    y2019_day10: 2019, 10 => "2019/10.rs",
+    y2019_day12: 2019, 12 => "2019/12.rs",
+    y2019_day14: 2019, 14 => "2019/14.rs",
+    y2019_day16: 2019, 16 => "2019/16.rs",
+    y2019_day18: 2019, 18 => "2019/18.rs",
+    y2019_day20: 2019, 20 => "2019/20.rs",
+    y2019_day22: 2019, 22 => "2019/22.rs",
+    y2019_day23: 2019, 23 => "2019/23.rs",
+    y2019_day24: 2019, 24 => "2019/24.rs",
+}
+
+const USAGE: &str = "cargo all [--release] -- [--year <year>] [--bench] [--baseline <file>] [--save-baseline <file>] [--threshold <percent>]";
+
+/// The year to run, every year when it is not given, and the options shared with the days.
+fn parse_args() -> Result<(Option<u16>, Options), pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let year = args.opt_value_from_str(["-y", "--year"])?;
+    let options = Options::parse(&mut args)?;
+    bench::finish(args)?;
+
+    Ok((year, options))
+}
 
 fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{day:02}");
+    let (year, options) = parse_args().unwrap_or_else(|e| bench::exit_with_usage(e, USAGE));
 
-            let mut args = vec!["run", "--bin", &day];
-            if cfg!(not(debug_assertions)) {
-                args.push("--release");
-            }
+    let mut solutions = solutions();
+    solutions.retain(|solution| year.is_none_or(|year| solution.year == year));
+    solutions.sort_by_key(|solution| (solution.year, solution.day));
+
+    if solutions.is_empty() {
+        eprintln!("No solutions for {}.", year.unwrap_or_default());
+        process::exit(1);
+    }
 
-            let cmd = Command::new("cargo").args(&args).output().unwrap();
+    let mut records = Vec::new();
+    let mut measurements = Vec::new();
+    let mut regressions = 0;
 
-            println!("----------");
-            println!("{ANSI_BOLD}| Day {day} |{ANSI_RESET}");
-            println!("----------");
+    for solution in &solutions {
+        let (year, day) = (solution.year, solution.day);
 
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
+        println!("---------------");
+        println!("{ANSI_BOLD}| {year} Day {day:02} |{ANSI_RESET}");
+        println!("---------------");
 
-            println!(
-                "{}",
-                if is_empty {
-                    "Not solved."
-                } else {
-                    output.trim()
+        let input = advent_of_code::try_read_file("inputs", year, day);
+        let Some(input) = input.filter(|input| !input.trim().is_empty()) else {
+            println!("No input.");
+            continue;
+        };
+
+        let answers = Answers::load(year, day);
+
+        for (part, solver) in solution.parts() {
+            let result = if options.bench {
+                let (result, measurement) =
+                    bench::report(year, day, part, solver, &input, &answers);
+
+                if let Some(Change::Regressed(_)) = bench::report_change(&measurement, &options) {
+                    regressions += 1;
                 }
-            );
+                measurements.push(measurement);
 
-            if is_empty {
-                0_f64
+                result
             } else {
-                advent_of_code::parse_exec_time(&output)
+                let (answer, duration) = runner::time(solver, &input);
+                let result = PartResult {
+                    part,
+                    answer,
+                    duration,
+                };
+                runner::print_result(&result, &answers);
+
+                result
+            };
+
+            let status = answers.check(part, result.answer.as_ref());
+            let record = Record::new(year, day, &result, status);
+            if let Err(e) = records::emit(&record) {
+                eprintln!("Failed to write record: {e}");
             }
-        })
-        .sum();
+            records.push(record);
+        }
+    }
+
+    let total = records::total_duration(&records);
+    println!(
+        "{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{:.2}ms{ANSI_RESET}",
+        total.as_secs_f64() * 1000_f64
+    );
+
+    if let Some(path) = &options.save_baseline {
+        match bench::save_baseline(path, &measurements) {
+            Ok(_) => println!("Saved baseline to \"{path}\"."),
+            Err(e) => eprintln!("Failed to save baseline: {e}"),
+        }
+    }
+
+    if regressions > 0 {
+        println!(
+            "{ANSI_BOLD}{regressions} part(s) regressed compared to the baseline.{ANSI_RESET}"
+        );
+    }
 
-    println!("{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total:.2}ms{ANSI_RESET}");
+    let wrong = records
+        .iter()
+        .filter(|record| record.status == Status::Wrong)
+        .count();
+    if wrong > 0 {
+        println!("{ANSI_BOLD}{wrong} part(s) gave a wrong answer.{ANSI_RESET}");
+        process::exit(1);
+    }
 }
@@ -0,0 +1,149 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+pub const PART_TWO_HEADING: &str = "## --- Part Two ---";
+
+/// The part of a puzzle description that belongs to `part`, including the answer given for it.
+/// `None` when the description does not contain that part yet.
+pub fn select_part(markdown: &str, part: u8) -> Option<&str> {
+    let split = markdown.find(PART_TWO_HEADING);
+
+    match (part, split) {
+        (1, Some(split)) => Some(markdown[..split].trim_end()),
+        (1, None) => Some(markdown),
+        (2, Some(split)) => Some(&markdown[split..]),
+        _ => None,
+    }
+}
+
+/// Switches to the given styles, which needs a reset when one of them was turned off.
+fn push_style(rendered: &mut String, emphasis: bool, code: bool, turned_on: bool) {
+    match turned_on {
+        true if code => rendered.push_str(ANSI_ITALIC),
+        true => rendered.push_str(ANSI_BOLD),
+        false => {
+            rendered.push_str(ANSI_RESET);
+            if emphasis {
+                rendered.push_str(ANSI_BOLD);
+            }
+            if code {
+                rendered.push_str(ANSI_ITALIC);
+            }
+        }
+    }
+}
+
+/// Styles the emphasis, inline code and links of a single line.
+fn render_inline(line: &str) -> String {
+    let mut rendered = String::new();
+    let mut emphasis = false;
+    let mut code = false;
+    let mut rest = line;
+
+    while let Some(char) = rest.chars().next() {
+        rest = &rest[char.len_utf8()..];
+
+        match char {
+            '`' => {
+                code = !code;
+                push_style(&mut rendered, emphasis, code, code);
+            }
+            '*' if !code => {
+                emphasis = !emphasis;
+                push_style(&mut rendered, emphasis, code, emphasis);
+            }
+            '[' if !code => match rest.split_once("](") {
+                Some((text, after)) if after.contains(')') => {
+                    rendered.push_str(&render_inline(text));
+                    if emphasis {
+                        rendered.push_str(ANSI_BOLD);
+                    }
+                    rest = &after[after.find(')').unwrap() + 1..];
+                }
+                _ => rendered.push(char),
+            },
+            _ => rendered.push(char),
+        }
+    }
+
+    if emphasis || code {
+        rendered.push_str(ANSI_RESET);
+    }
+
+    rendered
+}
+
+/// Renders puzzle markdown for the terminal: headings in bold, emphasis in bold, inline code in
+/// italics and code blocks indented.
+pub fn render(markdown: &str) -> String {
+    let mut in_block = false;
+
+    markdown
+        .lines()
+        .filter_map(|line| {
+            if line.starts_with("```") {
+                in_block = !in_block;
+                return None;
+            }
+
+            Some(match (in_block, line.strip_prefix("## ")) {
+                (true, _) => format!("    {line}"),
+                (false, Some(heading)) => format!("{ANSI_BOLD}{heading}{ANSI_RESET}"),
+                (false, None) => render_inline(line),
+            })
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PUZZLE: &str = "## --- Day 1: Fuel ---
+
+The answer is *`34241`* for [this](/2019/day/1/input) *mass*.
+
+```
+12 *not emphasized*
+```
+
+Your puzzle answer was `3393938`.
+
+## --- Part Two ---
+
+Fuel itself requires fuel.";
+
+    #[test]
+    fn test_select_part() {
+        assert!(select_part(PUZZLE, 1)
+            .unwrap()
+            .ends_with("Your puzzle answer was `3393938`."));
+        assert!(select_part(PUZZLE, 2)
+            .unwrap()
+            .starts_with("## --- Part Two ---"));
+        assert_eq!(select_part("## --- Day 1: Fuel ---", 2), None);
+    }
+
+    #[test]
+    fn test_render() {
+        let rendered = render(PUZZLE);
+        let lines = rendered.lines().collect::<Vec<_>>();
+
+        assert_eq!(
+            lines[0],
+            format!("{ANSI_BOLD}--- Day 1: Fuel ---{ANSI_RESET}")
+        );
+        assert_eq!(
+            lines[2],
+            format!(
+                "The answer is {ANSI_BOLD}{ANSI_ITALIC}34241{ANSI_RESET}{ANSI_BOLD}{ANSI_RESET} \
+                 for this {ANSI_BOLD}mass{ANSI_RESET}."
+            )
+        );
+        assert_eq!(lines[4], "    12 *not emphasized*");
+    }
+}
@@ -0,0 +1,328 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::runner::{Answer, PartResult};
+use std::collections::HashMap;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::time::Duration;
+use std::{env, fs, io};
+
+/// When set, every solved part is appended as a JSON line to the file at this path.
+pub const RECORDS_ENV: &str = "AOC_RECORDS";
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Status {
+    /// The answer matches the accepted answer in `src/answers`.
+    Correct,
+    Wrong,
+    /// There is an answer but no accepted answer to compare it to.
+    Unknown,
+    Unsolved,
+}
+
+impl Status {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Status::Correct => "correct",
+            Status::Wrong => "wrong",
+            Status::Unknown => "unknown",
+            Status::Unsolved => "unsolved",
+        }
+    }
+
+    fn from_str(value: &str) -> Option<Self> {
+        match value {
+            "correct" => Some(Status::Correct),
+            "wrong" => Some(Status::Wrong),
+            "unknown" => Some(Status::Unknown),
+            "unsolved" => Some(Status::Unsolved),
+            _ => None,
+        }
+    }
+}
+
+/// The machine-readable outcome of running one part of a day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<Answer>,
+    pub duration: Duration,
+    pub status: Status,
+}
+
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+
+    for char in value.chars() {
+        match char {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            char if char.is_control() => escaped.push_str(&format!("\\u{:04x}", char as u32)),
+            char => escaped.push(char),
+        }
+    }
+
+    escaped.push('"');
+    escaped
+}
+
+/// Reverses [`escape`] for a quoted string that makes up all of `value`. Only the escapes that
+/// `escape` writes are read, as records are only read back from files written by [`emit`].
+fn unescape(value: &str) -> Option<String> {
+    let mut chars = value.strip_prefix('"')?.strip_suffix('"')?.chars();
+    let mut unescaped = String::with_capacity(value.len());
+
+    while let Some(char) = chars.next() {
+        match char {
+            '\\' => match chars.next()? {
+                char @ ('"' | '\\') => unescaped.push(char),
+                'n' => unescaped.push('\n'),
+                'r' => unescaped.push('\r'),
+                't' => unescaped.push('\t'),
+                'u' => {
+                    let code = chars.by_ref().take(4).collect::<String>();
+                    (code.len() == 4 && code.chars().all(|digit| digit.is_ascii_hexdigit()))
+                        .then_some(())?;
+                    unescaped.push(char::from_u32(u32::from_str_radix(&code, 16).ok()?)?);
+                }
+                _ => return None,
+            },
+            '"' => return None,
+            char if char.is_control() => return None,
+            char => unescaped.push(char),
+        }
+    }
+
+    Some(unescaped)
+}
+
+/// The fields of a flat JSON object that only holds integers, such as `{"year":2019,"day":1}`.
+pub(crate) fn parse_numbers(line: &str) -> Option<HashMap<&str, i128>> {
+    let fields = line.trim().strip_prefix('{')?.strip_suffix('}')?;
+
+    fields
+        .split(',')
+        .map(|field| {
+            let (key, value) = field.split_once(':')?;
+            let key = key.trim().strip_prefix('"')?.strip_suffix('"')?;
+
+            Some((key, value.trim().parse().ok()?))
+        })
+        .collect()
+}
+
+impl Record {
+    pub fn new(year: u16, day: u8, result: &PartResult, status: Status) -> Self {
+        Self {
+            year,
+            day,
+            part: result.part,
+            answer: result.answer.clone(),
+            duration: result.duration,
+            status,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        let answer = match &self.answer {
+            None => "null".to_string(),
+            Some(Answer::Number(value)) => value.to_string(),
+            Some(Answer::Text(value)) => escape(value),
+        };
+
+        format!(
+            "{{\"year\":{},\"day\":{},\"part\":{},\"answer\":{},\"duration_ns\":{},\"status\":\"{}\"}}",
+            self.year,
+            self.day,
+            self.part,
+            answer,
+            self.duration.as_nanos(),
+            self.status.as_str()
+        )
+    }
+
+    /// Reads a line written by [`to_json`](Record::to_json). The answer is the only field that
+    /// can hold a string, so the fields around it are split off by their names.
+    pub fn from_json(line: &str) -> Option<Self> {
+        let (numbers, rest) = line.trim().split_once(",\"answer\":")?;
+        let (answer, rest) = rest.rsplit_once(",\"duration_ns\":")?;
+        let (duration, status) = rest.split_once(",\"status\":")?;
+
+        let numbers = format!("{numbers}}}");
+        let numbers = parse_numbers(&numbers)?;
+        let number = |key: &str| numbers.get(key).copied();
+
+        let answer = match answer {
+            "null" => None,
+            text if text.starts_with('"') => Some(Answer::Text(unescape(text)?)),
+            number => Some(Answer::Number(number.parse().ok()?)),
+        };
+
+        Some(Self {
+            year: number("year")?.try_into().ok()?,
+            day: number("day")?.try_into().ok()?,
+            part: number("part")?.try_into().ok()?,
+            answer,
+            duration: Duration::from_nanos(duration.parse().ok()?),
+            status: Status::from_str(&unescape(status.strip_suffix('}')?)?)?,
+        })
+    }
+}
+
+/// Appends the record to the file named by [`RECORDS_ENV`], if it is set.
+pub fn emit(record: &Record) -> io::Result<()> {
+    let Ok(path) = env::var(RECORDS_ENV) else {
+        return Ok(());
+    };
+
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{}", record.to_json())
+}
+
+/// Reads a file of JSON lines, skipping lines that are not records.
+pub fn read(path: &str) -> io::Result<Vec<Record>> {
+    Ok(fs::read_to_string(path)?
+        .lines()
+        .filter_map(Record::from_json)
+        .collect())
+}
+
+/// The total time spent on all solved parts.
+pub fn total_duration(records: &[Record]) -> Duration {
+    records
+        .iter()
+        .filter(|record| record.status != Status::Unsolved)
+        .map(|record| record.duration)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(answer: Option<Answer>, nanos: u64) -> Record {
+        Record {
+            year: 2019,
+            day: 8,
+            part: 2,
+            status: match answer {
+                Some(_) => Status::Unknown,
+                None => Status::Unsolved,
+            },
+            answer,
+            duration: Duration::from_nanos(nanos),
+        }
+    }
+
+    #[test]
+    fn test_to_json() {
+        assert_eq!(
+            record(Some(Answer::Number(-12)), 74).to_json(),
+            r#"{"year":2019,"day":8,"part":2,"answer":-12,"duration_ns":74,"status":"unknown"}"#
+        );
+        assert_eq!(
+            record(None, 3).to_json(),
+            r#"{"year":2019,"day":8,"part":2,"answer":null,"duration_ns":3,"status":"unsolved"}"#
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let records = [
+            record(Some(Answer::Number(1219070632396864)), 1_450_000),
+            record(Some(Answer::Text("say \"HI\"\n\\".to_string())), 0),
+            record(
+                Some(Answer::Text("\t\r\u{8}\u{c}\u{0}\u{1f}\u{7f}/".to_string())),
+                0,
+            ),
+            record(Some(Answer::Text("ÆØÅ 🎄".to_string())), 0),
+            record(
+                Some(Answer::Text(
+                    r#","answer":1,"duration_ns":2,"status":"wrong"}"#.to_string(),
+                )),
+                0,
+            ),
+            record(None, 10_300_000_000),
+            Record {
+                status: Status::Wrong,
+                ..record(Some(Answer::Number(7)), 5)
+            },
+        ];
+
+        for record in records {
+            assert_eq!(Record::from_json(&record.to_json()), Some(record));
+        }
+
+        assert_eq!(Record::from_json("🎄 Part 1 🎄"), None);
+        assert_eq!(Record::from_json(r#"{"year":2019,"day":1}"#), None);
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(
+            escape("say \"HI\"\n\\\t\u{1}"),
+            r#""say \"HI\"\n\\\t\u0001""#
+        );
+        assert_eq!(unescape(r#""\u00c6\u0000""#), Some("Æ\u{0}".to_string()));
+        assert_eq!(unescape(r#""\x""#), None);
+        assert_eq!(unescape(r#""\u00g0""#), None);
+        assert_eq!(unescape(r#""\u00""#), None);
+        assert_eq!(unescape(r#""a"b""#), None);
+        assert_eq!(unescape("\"a\nb\""), None);
+        assert_eq!(unescape("abc"), None);
+    }
+
+    #[test]
+    fn test_malformed_lines() {
+        let line = record(Some(Answer::Text("x".to_string())), 5).to_json();
+
+        for malformed in [
+            "",
+            "🎄 Part 1 🎄",
+            r#"{"year":2019,"day":1}"#,
+            &line[..line.len() - 1],
+            &line.replace("\"x\"", "\"x"),
+            &line.replace("\"x\"", "x"),
+            &line.replace("2019", "\"2019\""),
+            &line.replace("\"day\":8", "\"day\":800"),
+            &line.replace(":5,", ":-5,"),
+            &line.replace("unknown", "maybe"),
+        ] {
+            assert_eq!(Record::from_json(malformed), None, "{malformed}");
+        }
+
+        assert_eq!(
+            Record::from_json(&format!("  {line}  ")),
+            Some(record(Some(Answer::Text("x".to_string())), 5))
+        );
+    }
+
+    #[test]
+    fn test_parse_numbers() {
+        assert_eq!(
+            parse_numbers(r#"{"year": 2019, "day":-1}"#),
+            Some(HashMap::from([("year", 2019), ("day", -1)]))
+        );
+        assert_eq!(parse_numbers(r#"{"year":"2019"}"#), None);
+        assert_eq!(parse_numbers(r#"{"year":2019"#), None);
+    }
+
+    #[test]
+    fn test_total_duration() {
+        let records = [
+            record(Some(Answer::Number(1)), 74),
+            record(None, 1_000),
+            record(Some(Answer::Number(2)), 50),
+        ];
+
+        assert_eq!(total_duration(&records), Duration::from_nanos(124));
+    }
+}
@@ -0,0 +1,202 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::answers::Answers;
+use crate::records::Status;
+use crate::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+
+/// The answer to one part of a puzzle.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(value) => write!(f, "{value}"),
+            Answer::Text(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+macro_rules! impl_from_number {
+    ($($type:ty),*) => {
+        $(
+            impl From<$type> for Answer {
+                fn from(value: $type) -> Self {
+                    Answer::Number(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_number!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, i128);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+pub type Part = fn(&str) -> Option<Answer>;
+
+/// The two parts of the solution for a day, as registered with [`solutions!`](crate::solutions).
+#[derive(Copy, Clone)]
+pub struct Solution {
+    pub year: u16,
+    pub day: u8,
+    pub part_one: Part,
+    pub part_two: Part,
+}
+
+#[derive(Clone, Debug)]
+pub struct PartResult {
+    pub part: u8,
+    pub answer: Option<Answer>,
+    pub duration: Duration,
+}
+
+/// Runs `part` once, timing only the call itself.
+pub fn time(part: Part, input: &str) -> (Option<Answer>, Duration) {
+    let timer = Instant::now();
+    let answer = part(input);
+
+    (answer, timer.elapsed())
+}
+
+/// The answer followed by a mark that tells whether it matches the accepted answer.
+pub fn mark_answer(answer: &Answer, part: u8, answers: &Answers) -> String {
+    match answers.check(part, Some(answer)) {
+        Status::Correct => format!("{answer} ✅"),
+        Status::Wrong => format!(
+            "{answer} ❌ {ANSI_BOLD}expected {}{ANSI_RESET}",
+            answers.expected(part).unwrap_or_default()
+        ),
+        _ => answer.to_string(),
+    }
+}
+
+/// Prints the result the way `solve!` and the runner show it.
+pub fn print_result(result: &PartResult, answers: &Answers) {
+    println!("🎄 {ANSI_BOLD}Part {}{ANSI_RESET} 🎄", result.part);
+
+    match &result.answer {
+        Some(answer) => println!(
+            "{} {ANSI_ITALIC}(elapsed: {:.2?}){ANSI_RESET}",
+            mark_answer(answer, result.part, answers),
+            result.duration
+        ),
+        None => println!("not solved."),
+    }
+}
+
+impl Solution {
+    pub fn parts(&self) -> [(u8, Part); 2] {
+        [(1, self.part_one), (2, self.part_two)]
+    }
+
+    pub fn run(&self, input: &str) -> Vec<PartResult> {
+        self.parts()
+            .into_iter()
+            .map(|(part, solver)| {
+                let (answer, duration) = time(solver, input);
+
+                PartResult {
+                    part,
+                    answer,
+                    duration,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Includes the solution modules in `src/YYYY` into the calling binary and defines a
+/// `solutions()` function that lists them, so they can be run without spawning a process per day.
+/// Test builds leave the modules out, their tests already run in the binary of every day.
+///
+/// ```ignore
+/// advent_of_code::solutions! {
+///     y2019_day01: 2019, 1 => "2019/01.rs",
+/// }
+/// ```
+#[macro_export]
+macro_rules! solutions {
+    ($($module:ident: $year:literal, $day:literal => $path:literal),* $(,)?) => {
+        $(
+            #[cfg(not(test))]
+            #[allow(dead_code)]
+            #[path = $path]
+            mod $module;
+        )*
+
+        #[cfg(test)]
+        fn solutions() -> Vec<$crate::runner::Solution> {
+            Vec::new()
+        }
+
+        #[cfg(not(test))]
+        fn solutions() -> Vec<$crate::runner::Solution> {
+            vec![$(
+                $crate::runner::Solution {
+                    year: $year,
+                    day: $day,
+                    part_one: |input| $module::part_one(input).map(Into::into),
+                    part_two: |input| $module::part_two(input).map(Into::into),
+                }
+            ),*]
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answer_conversion() {
+        assert_eq!(Answer::from(42_usize), Answer::Number(42));
+        assert_eq!(Answer::from(-1_isize), Answer::Number(-1));
+        assert_eq!(Answer::from("ABC").to_string(), "ABC");
+    }
+
+    #[test]
+    fn test_mark_answer() {
+        let answers = Answers::new(Some("42".to_string()), None);
+
+        assert_eq!(mark_answer(&Answer::Number(42), 1, &answers), "42 ✅");
+        assert_eq!(
+            mark_answer(&Answer::Number(41), 1, &answers),
+            format!("41 ❌ {ANSI_BOLD}expected 42{ANSI_RESET}")
+        );
+        assert_eq!(mark_answer(&Answer::Number(41), 2, &answers), "41");
+    }
+
+    #[test]
+    fn test_run() {
+        let solution = Solution {
+            year: 2019,
+            day: 1,
+            part_one: |input| Some(input.len().into()),
+            part_two: |_| None,
+        };
+        let results = solution.run("abc");
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].answer, Some(Answer::Number(3)));
+        assert_eq!(results[1].part, 2);
+        assert_eq!(results[1].answer, None);
+    }
+}
@@ -0,0 +1,369 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt::Display;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// What the server said about a submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint in which direction.
+    Incorrect,
+    /// The answer was not checked because the last wrong answer was too recent.
+    Wait(Duration),
+    /// The part is already solved or not unlocked yet.
+    WrongLevel,
+    Unknown(String),
+}
+
+impl Outcome {
+    fn as_str(&self) -> &str {
+        match self {
+            Outcome::Correct => "correct",
+            Outcome::TooHigh => "too-high",
+            Outcome::TooLow => "too-low",
+            Outcome::Incorrect => "incorrect",
+            Outcome::Wait(_) => "wait",
+            Outcome::WrongLevel => "wrong-level",
+            Outcome::Unknown(_) => "unknown",
+        }
+    }
+
+    /// Whether the server judged the answer, rather than refusing to check it.
+    pub fn is_judgement(&self) -> bool {
+        matches!(
+            self,
+            Outcome::Correct | Outcome::TooHigh | Outcome::TooLow | Outcome::Incorrect
+        )
+    }
+
+    fn from_str(value: &str) -> Option<Self> {
+        match value {
+            "correct" => Some(Outcome::Correct),
+            "too-high" => Some(Outcome::TooHigh),
+            "too-low" => Some(Outcome::TooLow),
+            "incorrect" => Some(Outcome::Incorrect),
+            _ => None,
+        }
+    }
+
+    /// Reads the outcome from the response to a submission.
+    pub fn parse(response: &str) -> Self {
+        let lowercase = response.to_lowercase();
+
+        if lowercase.contains("that's the right answer") {
+            Outcome::Correct
+        } else if lowercase.contains("too high") {
+            Outcome::TooHigh
+        } else if lowercase.contains("too low") {
+            Outcome::TooLow
+        } else if lowercase.contains("that's not the right answer") {
+            Outcome::Incorrect
+        } else if lowercase.contains("answer too recently") || lowercase.contains("please wait") {
+            Outcome::Wait(parse_wait(&lowercase).unwrap_or(Duration::from_secs(60)))
+        } else if lowercase.contains("solving the right level") {
+            Outcome::WrongLevel
+        } else {
+            Outcome::Unknown(response.trim().to_string())
+        }
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "that's the right answer!"),
+            Outcome::TooHigh => write!(f, "the answer is too high."),
+            Outcome::TooLow => write!(f, "the answer is too low."),
+            Outcome::Incorrect => write!(f, "that's not the right answer."),
+            Outcome::Wait(duration) => {
+                write!(f, "wait {}s before submitting again.", duration.as_secs())
+            }
+            Outcome::WrongLevel => write!(f, "this part is already solved or still locked."),
+            Outcome::Unknown(response) => write!(f, "unrecognized response: {response}"),
+        }
+    }
+}
+
+/// The time to wait from phrases like `you have 1m 23s left to wait` or `please wait one minute`.
+fn parse_wait(response: &str) -> Option<Duration> {
+    let words = response
+        .split(|char: char| !char.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>();
+
+    let number = |word: &str| match word {
+        "one" | "a" => Some(1),
+        "two" => Some(2),
+        "five" => Some(5),
+        "ten" => Some(10),
+        word => word.parse::<u64>().ok(),
+    };
+
+    let mut seconds = None;
+    for (index, word) in words.iter().enumerate() {
+        let previous = || {
+            index
+                .checked_sub(1)
+                .and_then(|previous| number(words[previous]))
+        };
+
+        let found = if word.starts_with("minute") {
+            previous().map(|minutes| minutes * 60)
+        } else if word.starts_with("second") {
+            previous()
+        } else if let Some(value) = word.strip_suffix('m') {
+            value.parse::<u64>().ok().map(|minutes| minutes * 60)
+        } else if let Some(value) = word.strip_suffix('s') {
+            value.parse::<u64>().ok()
+        } else {
+            None
+        };
+
+        if let Some(found) = found {
+            seconds = Some(seconds.unwrap_or(0) + found);
+        }
+    }
+
+    seconds.map(Duration::from_secs)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Guess {
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+}
+
+/// Why a guess is not sent to the server.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadySolved(String),
+    AlreadyGuessed(Outcome),
+    /// The answer is at least as high as an answer that was too high.
+    AboveBound(i128),
+    /// The answer is at most as low as an answer that was too low.
+    BelowBound(i128),
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::AlreadySolved(answer) => {
+                write!(f, "this part is already solved with \"{answer}\".")
+            }
+            Refusal::AlreadyGuessed(outcome) => {
+                write!(f, "this answer was already submitted: {outcome}")
+            }
+            Refusal::AboveBound(bound) => {
+                write!(
+                    f,
+                    "the answer must be lower than {bound}, which was too high."
+                )
+            }
+            Refusal::BelowBound(bound) => {
+                write!(
+                    f,
+                    "the answer must be higher than {bound}, which was too low."
+                )
+            }
+        }
+    }
+}
+
+/// Every answer submitted for a day, stored in `src/YYYY/guesses/DD.txt` as lines of the part, the
+/// outcome and the answer separated by spaces.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct History {
+    guesses: Vec<Guess>,
+}
+
+fn history_path(year: u16, day: u8) -> PathBuf {
+    crate::year_path(year)
+        .join("guesses")
+        .join(format!("{day:02}.txt"))
+}
+
+impl History {
+    pub fn parse(contents: &str) -> Self {
+        let guesses = contents
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.splitn(3, ' ');
+
+                Some(Guess {
+                    part: fields.next()?.parse().ok()?,
+                    outcome: Outcome::from_str(fields.next()?)?,
+                    answer: fields.next()?.to_string(),
+                })
+            })
+            .collect();
+
+        Self { guesses }
+    }
+
+    pub fn load(year: u16, day: u8) -> io::Result<Self> {
+        match fs::read_to_string(history_path(year, day)) {
+            Ok(contents) => Ok(Self::parse(&contents)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn guesses(&self) -> &[Guess] {
+        &self.guesses
+    }
+
+    /// Adds the guess to the history of the day, skipping responses that did not judge it.
+    pub fn record(&mut self, year: u16, day: u8, guess: Guess) -> io::Result<()> {
+        if !guess.outcome.is_judgement() {
+            return Ok(());
+        }
+
+        let path = history_path(year, day);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(
+            file,
+            "{} {} {}",
+            guess.part,
+            guess.outcome.as_str(),
+            guess.answer
+        )?;
+
+        self.guesses.push(guess);
+        Ok(())
+    }
+
+    /// Checks the guess against earlier guesses for the same part before submitting it.
+    pub fn check(&self, part: u8, answer: &str) -> Result<(), Refusal> {
+        let guesses = self.guesses.iter().filter(|guess| guess.part == part);
+
+        if let Some(correct) = guesses
+            .clone()
+            .find(|guess| guess.outcome == Outcome::Correct)
+        {
+            return Err(Refusal::AlreadySolved(correct.answer.clone()));
+        }
+
+        if let Some(guess) = guesses.clone().find(|guess| guess.answer == answer) {
+            return Err(Refusal::AlreadyGuessed(guess.outcome.clone()));
+        }
+
+        let Ok(value) = answer.parse::<i128>() else {
+            return Ok(());
+        };
+
+        let bound = |outcome: Outcome| {
+            guesses
+                .clone()
+                .filter(move |guess| guess.outcome == outcome)
+                .filter_map(|guess| guess.answer.parse::<i128>().ok())
+        };
+
+        if let Some(upper) = bound(Outcome::TooHigh)
+            .min()
+            .filter(|upper| value >= *upper)
+        {
+            return Err(Refusal::AboveBound(upper));
+        }
+
+        if let Some(lower) = bound(Outcome::TooLow).max().filter(|lower| value <= *lower) {
+            return Err(Refusal::BelowBound(lower));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_outcome() {
+        assert_eq!(
+            Outcome::parse("That's the right answer! You are one gold star closer."),
+            Outcome::Correct
+        );
+        assert_eq!(
+            Outcome::parse("That's not the right answer; your answer is too high."),
+            Outcome::TooHigh
+        );
+        assert_eq!(
+            Outcome::parse("That's not the right answer; your answer is too low."),
+            Outcome::TooLow
+        );
+        assert_eq!(
+            Outcome::parse(
+                "That's not the right answer. Please wait one minute before trying again."
+            ),
+            Outcome::Incorrect
+        );
+        assert_eq!(
+            Outcome::parse("You gave an answer too recently; you have 1m 23s left to wait."),
+            Outcome::Wait(Duration::from_secs(83))
+        );
+        assert_eq!(
+            Outcome::parse("You gave an answer too recently; you have 42s left to wait."),
+            Outcome::Wait(Duration::from_secs(42))
+        );
+        assert_eq!(
+            Outcome::parse(
+                "You don't seem to be solving the right level. Did you already complete it?"
+            ),
+            Outcome::WrongLevel
+        );
+    }
+
+    #[test]
+    fn test_parse_wait() {
+        assert_eq!(
+            parse_wait("please wait 5 minutes before trying again."),
+            Some(Duration::from_secs(300))
+        );
+        assert_eq!(
+            parse_wait("wait a minute as 2 seconds pass"),
+            Some(Duration::from_secs(62))
+        );
+        assert_eq!(parse_wait("nothing to see"), None);
+    }
+
+    #[test]
+    fn test_history() {
+        let history =
+            History::parse("1 too-high 500\n1 too-low 100\n1 incorrect 250\n2 correct HELLO");
+
+        assert_eq!(history.guesses().len(), 4);
+        assert_eq!(
+            history.check(1, "250"),
+            Err(Refusal::AlreadyGuessed(Outcome::Incorrect))
+        );
+        assert_eq!(
+            history.check(1, "500"),
+            Err(Refusal::AlreadyGuessed(Outcome::TooHigh))
+        );
+        assert_eq!(history.check(1, "600"), Err(Refusal::AboveBound(500)));
+        assert_eq!(
+            history.check(1, "100"),
+            Err(Refusal::AlreadyGuessed(Outcome::TooLow))
+        );
+        assert_eq!(history.check(1, "50"), Err(Refusal::BelowBound(100)));
+        assert_eq!(history.check(1, "300"), Ok(()));
+        assert_eq!(history.check(1, "ABC"), Ok(()));
+        assert_eq!(
+            history.check(2, "WORLD"),
+            Err(Refusal::AlreadySolved("HELLO".to_string()))
+        );
+    }
+}